@@ -19,12 +19,22 @@ export const language = <IMonarchLanguage> {
         'abstract', 'block', 'optional',
         'if', 'else',
         'true', 'false',
-        'allow', 'never_allow', 'audit_allow', 'dont_audit'
+        'allow', 'never_allow', 'audit_allow', 'dont_audit',
+        'allow_xperm', 'never_allow_xperm', 'audit_allow_xperm', 'dont_audit_xperm', 'ioctl',
+        'class', 'common', 'class_order',
+        'type_transition', 'type_change', 'type_member',
+        'role_type', 'role_allow', 'role_transition',
+        'user_role', 'user_level', 'user_range', 'sensitivity_order', 'category_order', 'sensitivity_category',
+        'constrain', 'mls_constrain', 'validate_trans', 'mls_validate_trans', 'dom', 'domby', 'incomp',
+        'file_context', 'fs_use_xattr', 'fs_use_task', 'fs_use_trans', 'genfscon',
+        'port_context', 'netif_context', 'node_context', 'ibpkey_context', 'ibendport_context',
+        'sid_order', 'sid_context', 'import'
     ],
     typeKeywords: [
-        'type', 'type_attribute', 'type_alias',
+        'type', 'type_attribute', 'type_alias', 'type_bounds', 'permissive',
         'role', 'role_attribute',
-        'user', 'user_attribute'
+        'user', 'user_attribute',
+        'class_permission', 'sensitivity_alias', 'category_alias', 'bool', 'tunable', 'sid'
     ],
 
     operators: [
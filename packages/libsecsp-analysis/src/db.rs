@@ -6,9 +6,18 @@ use std::{fs, io};
 use rustc_hash::FxHashSet;
 
 use crate::cancellation::{Canceled, CheckCanceled};
+use crate::imports::ImportsDatabase;
 use crate::input::{FilesDatabase, SourceRoot};
-
-#[salsa::database(crate::input::Files, crate::syntax::Syntax)]
+use crate::syntax::SyntaxDatabase;
+
+#[salsa::database(
+    crate::input::Files,
+    crate::syntax::Syntax,
+    crate::imports::Imports,
+    crate::macros::Macros,
+    crate::mls::Mls,
+    crate::toggles::Toggles
+)]
 #[derive(Debug)]
 pub struct AnalysisDatabase {
     runtime: salsa::Runtime<AnalysisDatabase>,
@@ -78,18 +87,34 @@ impl AnalysisDatabase {
         Self::from_files(ws_files)
     }
 
+    /// Load [ws_files] along with every file that they import, directly or transitively.
+    /// Imported files that don't exist are left out, and reported by the import diagnostics.
     pub fn from_files(ws_files: Vec<PathBuf>) -> Result<Self, io::Error> {
         let mut db = AnalysisDatabase::default();
         let mut source_root = FxHashSet::default();
+        let mut pending = ws_files;
 
-        for ws_file in ws_files {
+        while let Some(ws_file) = pending.pop() {
             let id = db.file_path(ws_file.clone());
+
+            if !source_root.insert(id) {
+                continue;
+            }
+
             let mut file = fs::File::open(ws_file.clone())?;
             let mut contents = String::new();
             file.read_to_string(&mut contents)?;
 
             db.set_file_text(id, Arc::new(contents));
-            source_root.insert(id);
+            db.set_reparsed_file(id, None);
+
+            for import in db.file_imports(id).iter() {
+                let path = db.lookup_file_path(import.file_id());
+
+                if path.is_file() {
+                    pending.push(path);
+                }
+            }
         }
 
         db.set_source_root(Arc::new(SourceRoot(source_root)));
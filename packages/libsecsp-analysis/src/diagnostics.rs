@@ -0,0 +1,33 @@
+use std::fmt;
+
+use text_unit::TextRange;
+
+/// A problem found in an otherwise syntactically valid source file.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Diagnostic {
+    message: String,
+    range: TextRange,
+}
+
+impl Diagnostic {
+    pub fn new<S: Into<String>>(message: S, range: TextRange) -> Self {
+        Diagnostic {
+            message: message.into(),
+            range,
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn range(&self) -> TextRange {
+        self.range
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
@@ -0,0 +1,212 @@
+//! Resolution of the files named by `import` statements, and the graph of dependencies that
+//! they form between files.
+
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+
+use rustc_hash::FxHashSet;
+use text_unit::TextRange;
+
+use secsp_syntax::ast::{AstNode, ImportStmt, ItemOwner};
+
+use crate::diagnostics::Diagnostic;
+use crate::input::FileId;
+use crate::syntax::SyntaxDatabase;
+
+#[salsa::query_group(Imports)]
+pub trait ImportsDatabase: SyntaxDatabase {
+    /// Find the files imported directly by a single file.
+    fn file_imports(&self, file_id: FileId) -> Arc<Vec<Import>>;
+
+    /// Find every file that a file depends on, directly or transitively. Each file comes after
+    /// the files that it imports, with the file itself last.
+    fn import_order(&self, file_id: FileId) -> Arc<Vec<FileId>>;
+
+    /// Check that the files imported by a file exist and don't import it in turn.
+    fn import_diagnostics(&self, file_id: FileId) -> Arc<Vec<Diagnostic>>;
+}
+
+/// A file imported by an `import` statement.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Import {
+    file_id: FileId,
+    range: TextRange,
+}
+
+impl Import {
+    pub fn file_id(&self) -> FileId {
+        self.file_id
+    }
+
+    /// Get the range of the imported path within the importing file.
+    pub fn range(&self) -> TextRange {
+        self.range
+    }
+}
+
+/// Find the path of the file that [importer] refers to as [target]. Relative paths are relative
+/// to the directory of the importing file.
+pub fn resolve_import(importer: &Path, target: &str) -> PathBuf {
+    let base = importer.parent().unwrap_or_else(|| Path::new(""));
+    let mut resolved = PathBuf::new();
+
+    // Remove `.` and `..` components, so that each file is interned under a single path.
+    for component in base.join(target).components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir if resolved.file_name().is_some() => {
+                resolved.pop();
+            }
+            component => resolved.push(component.as_os_str()),
+        }
+    }
+
+    resolved
+}
+
+fn file_imports(db: &impl ImportsDatabase, file_id: FileId) -> Arc<Vec<Import>> {
+    let path = db.lookup_file_path(file_id);
+    let file = db.source_file(file_id).tree();
+
+    let imports = file
+        .items_of::<ImportStmt>()
+        .filter_map(|stmt| {
+            let target = stmt.target()?;
+            let range = stmt.path()?.syntax().text_range();
+
+            Some(Import {
+                file_id: db.file_path(resolve_import(&path, &target)),
+                range,
+            })
+        })
+        .collect();
+
+    Arc::new(imports)
+}
+
+fn import_order(db: &impl ImportsDatabase, file_id: FileId) -> Arc<Vec<FileId>> {
+    fn visit(
+        db: &impl ImportsDatabase,
+        file_id: FileId,
+        visited: &mut FxHashSet<FileId>,
+        order: &mut Vec<FileId>,
+    ) {
+        // Files that were already visited are skipped, which also stops cycles from recursing.
+        if !visited.insert(file_id) {
+            return;
+        }
+
+        let source_root = db.source_root();
+
+        for import in db.file_imports(file_id).iter() {
+            if source_root.0.contains(&import.file_id) {
+                visit(db, import.file_id, visited, order);
+            }
+        }
+
+        order.push(file_id);
+    }
+
+    let mut order = vec![];
+    visit(db, file_id, &mut FxHashSet::default(), &mut order);
+
+    Arc::new(order)
+}
+
+fn import_diagnostics(db: &impl ImportsDatabase, file_id: FileId) -> Arc<Vec<Diagnostic>> {
+    let source_root = db.source_root();
+    let mut diagnostics = vec![];
+
+    for import in db.file_imports(file_id).iter() {
+        let path = db.lookup_file_path(import.file_id);
+
+        let message = if !source_root.0.contains(&import.file_id) {
+            format!("unable to find imported file `{}`", path.display())
+        } else if db.import_order(import.file_id).contains(&file_id) {
+            format!("importing `{}` creates a cycle", path.display())
+        } else {
+            continue;
+        };
+
+        diagnostics.push(Diagnostic::new(message, import.range));
+    }
+
+    Arc::new(diagnostics)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use super::*;
+    use crate::AnalysisHost;
+
+    fn diagnostics(host: &AnalysisHost, file_id: FileId) -> Vec<String> {
+        host.analysis()
+            .diagnostics(file_id)
+            .expect("canceled")
+            .into_iter()
+            .map(|diagnostic| diagnostic.message().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn resolve_relative_paths() {
+        let importer = Path::new("policy/services/httpd.csp");
+
+        assert_eq!(
+            PathBuf::from("policy/services/apache.csp"),
+            resolve_import(importer, "./apache.csp")
+        );
+        assert_eq!(
+            PathBuf::from("policy/base.csp"),
+            resolve_import(importer, "../base.csp")
+        );
+    }
+
+    #[test]
+    fn dependencies_come_first() {
+        let mut host = AnalysisHost::default();
+        let main = host.add_file(
+            PathBuf::from("main.csp"),
+            "import \"b.csp\"; import \"a.csp\";".to_string(),
+        );
+        let a = host.add_file(PathBuf::from("a.csp"), "type a;".to_string());
+        let b = host.add_file(PathBuf::from("b.csp"), "import \"a.csp\";".to_string());
+
+        let order = host.analysis().import_order(main).expect("canceled");
+
+        assert_eq!(vec![a, b, main], order);
+        assert!(diagnostics(&host, main).is_empty());
+    }
+
+    #[test]
+    fn missing_import() {
+        let mut host = AnalysisHost::default();
+        let main = host.add_file(
+            PathBuf::from("policy/main.csp"),
+            "import \"missing.csp\";".to_string(),
+        );
+
+        assert_eq!(
+            vec!["unable to find imported file `policy/missing.csp`"],
+            diagnostics(&host, main)
+        );
+    }
+
+    #[test]
+    fn import_cycle() {
+        let mut host = AnalysisHost::default();
+        let a = host.add_file(PathBuf::from("a.csp"), "import \"b.csp\";".to_string());
+        let b = host.add_file(PathBuf::from("b.csp"), "import \"a.csp\";".to_string());
+
+        assert_eq!(
+            vec!["importing `b.csp` creates a cycle"],
+            diagnostics(&host, a)
+        );
+        assert_eq!(
+            vec!["importing `a.csp` creates a cycle"],
+            diagnostics(&host, b)
+        );
+    }
+}
@@ -10,16 +10,27 @@ use salsa::ParallelDatabase;
 use secsp_syntax::{ast, Parse};
 
 use crate::cancellation::{Canceled, CheckCanceled};
+use crate::diagnostics::Diagnostic;
+use crate::imports::ImportsDatabase;
 use crate::input::{FileId, FilesDatabase, SourceRoot};
+use crate::macros::MacrosDatabase;
+use crate::mls::MlsDatabase;
 use crate::syntax::SyntaxDatabase;
+use crate::toggles::TogglesDatabase;
 
 pub mod cancellation;
 pub mod db;
+pub mod diagnostics;
+pub mod imports;
 pub mod input;
+pub mod macros;
+pub mod mls;
 pub mod syntax;
+pub mod toggles;
 
 pub use db::AnalysisDatabase;
 use rustc_hash::FxHashSet;
+pub use secsp_syntax::TextEdit;
 
 #[derive(Debug)]
 pub struct AnalysisHost {
@@ -38,6 +49,14 @@ impl Analysis {
         self.with_db(|db| db.file_path(path))
     }
 
+    pub fn file_path(&self, file_id: FileId) -> Cancelable<PathBuf> {
+        self.with_db(|db| db.lookup_file_path(file_id))
+    }
+
+    pub fn file_text(&self, file_id: FileId) -> Cancelable<Arc<String>> {
+        self.with_db(|db| db.file_text(file_id))
+    }
+
     pub fn source_file(&self, file_id: FileId) -> Cancelable<Parse<ast::SourceFile>> {
         self.with_db(|db| db.source_file(file_id))
     }
@@ -46,6 +65,23 @@ impl Analysis {
         self.with_db(|db| (*db.source_root()).clone())
     }
 
+    /// Find the files that [file_id] depends on through `import` statements, in the order that
+    /// they should be processed. The file itself is last.
+    pub fn import_order(&self, file_id: FileId) -> Cancelable<Vec<FileId>> {
+        self.with_db(|db| (*db.import_order(file_id)).clone())
+    }
+
+    pub fn diagnostics(&self, file_id: FileId) -> Cancelable<Vec<Diagnostic>> {
+        self.with_db(|db| {
+            let mut diagnostics = (*db.import_diagnostics(file_id)).clone();
+            diagnostics.extend(db.macro_diagnostics(file_id).iter().cloned());
+            diagnostics.extend(db.mls_diagnostics(file_id).iter().cloned());
+            diagnostics.extend(db.toggle_diagnostics(file_id).iter().cloned());
+            diagnostics.sort_by_key(|diagnostic| diagnostic.range().start());
+            diagnostics
+        })
+    }
+
     fn with_db<F: FnOnce(&AnalysisDatabase) -> T + std::panic::UnwindSafe, T>(
         &self,
         f: F,
@@ -73,12 +109,24 @@ impl AnalysisHost {
 
         self.db.set_source_root(Arc::new(source_root));
         self.db.set_file_text(id, Arc::new(contents));
+        self.db.set_reparsed_file(id, None);
 
         id
     }
 
     pub fn update_file(&mut self, id: FileId, contents: String) {
         self.db.set_file_text(id, Arc::new(contents));
+        self.db.set_reparsed_file(id, None);
+    }
+
+    /// Apply [edit] to the text of a file, reparsing only the part of its tree that the edit
+    /// affects.
+    pub fn edit_file(&mut self, id: FileId, edit: &TextEdit) {
+        let parse = self.db.source_file(id).reparse(edit);
+        let contents = edit.apply(&self.db.file_text(id));
+
+        self.db.set_file_text(id, Arc::new(contents));
+        self.db.set_reparsed_file(id, Some(parse));
     }
 
     pub fn analysis(&self) -> Analysis {
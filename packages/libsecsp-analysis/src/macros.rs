@@ -0,0 +1,630 @@
+//! Binding of the arguments of macro calls to the parameters of the macros they call, and
+//! checks that each call supplies every parameter exactly once with an argument of its type.
+
+use std::sync::Arc;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use secsp_syntax::ast::{
+    self, AstNode, ClassDef, ContainerDef, Expr, Item, ItemOwner, KeywordKind, LiteralKind,
+    MacroCall, MacroDef, MacroParam, NameOwner, PathExpr, SourceFile, TypeAliasDef, VariableDef,
+};
+
+use crate::diagnostics::Diagnostic;
+use crate::input::FileId;
+use crate::syntax::SyntaxDatabase;
+
+#[salsa::query_group(Macros)]
+pub trait MacrosDatabase: SyntaxDatabase {
+    /// Find the kinds of symbol that each name in a single file is declared as.
+    fn file_symbol_kinds(&self, file_id: FileId) -> Arc<SymbolKinds>;
+
+    /// Find the kinds of symbol that each name is declared as across every file in the source
+    /// root.
+    fn symbol_kinds(&self) -> Arc<SymbolKinds>;
+
+    /// Check the parameters of the macros defined in a file, and the arguments of the macro
+    /// calls in it.
+    fn macro_diagnostics(&self, file_id: FileId) -> Arc<Vec<Diagnostic>>;
+}
+
+/// The kinds of symbol that each name is declared as, which can be more than one when the same
+/// name is declared in different containers.
+pub type SymbolKinds = FxHashMap<String, Vec<KeywordKind>>;
+
+/// The macros defined across a set of source files, which macro calls can be resolved against.
+#[derive(Debug, Default, Clone)]
+pub struct MacroTable {
+    /// Each macro with its path from the root of the file, grouped by name.
+    by_name: FxHashMap<String, Vec<(Vec<String>, MacroDef)>>,
+}
+
+impl MacroTable {
+    pub fn new<I: IntoIterator<Item = SourceFile>>(files: I) -> Self {
+        let mut by_name: FxHashMap<String, Vec<_>> = FxHashMap::default();
+
+        for file in files {
+            for macro_def in ast::descendants(file.syntax()).filter_map(MacroDef::cast) {
+                let name = match macro_def.name() {
+                    Some(name) => name,
+                    None => continue,
+                };
+
+                let mut path: Vec<String> = macro_def
+                    .syntax()
+                    .ancestors()
+                    .filter_map(ContainerDef::cast)
+                    .filter_map(|container| container.name())
+                    .collect();
+
+                path.reverse();
+                path.push(name.clone());
+                by_name.entry(name).or_default().push((path, macro_def));
+            }
+        }
+
+        MacroTable { by_name }
+    }
+
+    /// Find the macro that [path] calls. Macros are matched on their name, and then on the
+    /// containers that qualify the path if more than one macro has that name. Calls that can't
+    /// be narrowed down to a single macro, such as calls to macros inherited from another
+    /// container, are left unresolved.
+    pub fn resolve(&self, path: &PathExpr) -> Option<&MacroDef> {
+        let segments = path.segments();
+        let candidates = self.by_name.get(segments.last()?)?;
+
+        match candidates.as_slice() {
+            [(_, macro_def)] => Some(macro_def),
+            _ => {
+                let mut matching = candidates
+                    .iter()
+                    .filter(|(macro_path, _)| macro_path.ends_with(&segments));
+
+                match (matching.next(), matching.next()) {
+                    (Some((_, macro_def)), None) => Some(macro_def),
+                    _ => None,
+                }
+            }
+        }
+    }
+}
+
+/// Bind the arguments of [call] to the parameters of [macro_def], in the order of the
+/// parameters. Parameters without an argument take their default value.
+pub fn bind_arguments(
+    macro_def: &MacroDef,
+    call: &MacroCall,
+) -> Result<Vec<(MacroParam, Expr)>, Vec<Diagnostic>> {
+    let name = macro_def.name().unwrap_or_default();
+    let params: Vec<MacroParam> = macro_def
+        .param_list()
+        .into_iter()
+        .flat_map(|list| list.params())
+        .collect();
+
+    let mut values: Vec<Option<Expr>> = vec![None; params.len()];
+    let mut diagnostics = vec![];
+    let mut position = 0;
+    let mut seen_named = false;
+
+    for argument in call.arguments() {
+        let range = argument.syntax().text_range();
+
+        // Arguments that failed to parse have already been reported.
+        let value = match argument.value() {
+            Some(value) => value,
+            None => continue,
+        };
+
+        let idx = match argument.name() {
+            Some(param_name) => {
+                seen_named = true;
+
+                match params
+                    .iter()
+                    .position(|param| param.name().as_ref() == Some(&param_name))
+                {
+                    Some(idx) => idx,
+                    None => {
+                        diagnostics.push(Diagnostic::new(
+                            format!("macro `{}` has no parameter named `{}`", name, param_name),
+                            range,
+                        ));
+                        continue;
+                    }
+                }
+            }
+            None if seen_named => {
+                diagnostics.push(Diagnostic::new(
+                    "positional argument follows a named argument",
+                    range,
+                ));
+                continue;
+            }
+            None => {
+                position += 1;
+
+                if position > params.len() {
+                    diagnostics.push(Diagnostic::new(
+                        format!(
+                            "macro `{}` takes {} argument(s), but more were given",
+                            name,
+                            params.len()
+                        ),
+                        range,
+                    ));
+                    continue;
+                }
+
+                position - 1
+            }
+        };
+
+        if values[idx].is_some() {
+            diagnostics.push(Diagnostic::new(
+                format!(
+                    "argument for parameter `{}` is given more than once",
+                    params[idx].name().unwrap_or_default()
+                ),
+                range,
+            ));
+        } else {
+            values[idx] = Some(value);
+        }
+    }
+
+    let mut bound = vec![];
+
+    for (param, value) in params.into_iter().zip(values) {
+        match value.or_else(|| param.default_value()) {
+            Some(value) => bound.push((param, value)),
+            None => diagnostics.push(Diagnostic::new(
+                format!(
+                    "missing argument for parameter `{}` of macro `{}`",
+                    param.name().unwrap_or_default(),
+                    name
+                ),
+                call.syntax().text_range(),
+            )),
+        }
+    }
+
+    if diagnostics.is_empty() {
+        Ok(bound)
+    } else {
+        Err(diagnostics)
+    }
+}
+
+/// Qualify [path], written in a parameter's default value in [macro_def], with the container
+/// around the macro that declares it. The result names the same symbol from any call site.
+/// Global paths, and paths not declared in an enclosing container, are returned as written.
+pub fn qualify_default_path(macro_def: &MacroDef, path: &PathExpr) -> String {
+    let segments = path.segments();
+    let first = match segments.first() {
+        Some(first) if !path.is_global() => first,
+        _ => return path.path(),
+    };
+
+    let containers: Vec<ContainerDef> = macro_def
+        .syntax()
+        .ancestors()
+        .filter_map(ContainerDef::cast)
+        .collect();
+
+    for (idx, container) in containers.iter().enumerate() {
+        let declared = container.items().any(|item| {
+            let name = match item {
+                Item::Container(def) => def.name(),
+                Item::Macro(def) => def.name(),
+                Item::Variable(def) => def.name(),
+                Item::TypeAlias(def) => def.name(),
+                Item::Class(def) => def.name(),
+                Item::Common(def) => def.name(),
+                _ => None,
+            };
+
+            name.as_ref() == Some(first)
+        });
+
+        if declared {
+            let mut qualified: Vec<String> = containers[idx..]
+                .iter()
+                .rev()
+                .filter_map(|container| container.name())
+                .collect();
+
+            qualified.extend(segments);
+            return qualified.join(".");
+        }
+    }
+
+    path.path()
+}
+
+/// Check that parameter names are unique, and that parameters with default values come after
+/// the parameters that have to be supplied, since those can otherwise only be passed by name.
+fn param_diagnostics(macro_def: &MacroDef, diagnostics: &mut Vec<Diagnostic>) {
+    let mut names = FxHashSet::default();
+    let mut seen_default = false;
+
+    for param in macro_def.param_list().iter().flat_map(|list| list.params()) {
+        let name = match param.name() {
+            Some(name) => name,
+            None => continue,
+        };
+
+        let range = param.syntax().text_range();
+
+        if !names.insert(name.clone()) {
+            diagnostics.push(Diagnostic::new(
+                format!("duplicate parameter `{}`", name),
+                range,
+            ));
+        }
+
+        if param.default_value().is_some() {
+            seen_default = true;
+        } else if seen_default {
+            diagnostics.push(Diagnostic::new(
+                format!(
+                    "parameter `{}` has no default value, but follows a parameter that does",
+                    name
+                ),
+                range,
+            ));
+        }
+    }
+}
+
+/// Get the kind that a symbol declared as [kind] is passed to macros as, e.g. attributes and
+/// aliases are passed as the kind of symbol that they group or rename.
+fn param_kind(kind: KeywordKind) -> KeywordKind {
+    use secsp_syntax::ast::KeywordKind::*;
+
+    match kind {
+        TypeAttribute | TypeAlias => Type,
+        RoleAttribute => Role,
+        UserAttribute => User,
+        SensitivityAlias => Sensitivity,
+        CategoryAlias => Category,
+        Tunable => Bool,
+        kind => kind,
+    }
+}
+
+/// Check if an argument of kind [found] can be passed to a parameter of kind [expected].
+fn is_assignable(expected: KeywordKind, found: KeywordKind) -> bool {
+    use secsp_syntax::ast::KeywordKind::*;
+
+    match (param_kind(expected), found) {
+        (CategorySet, Category) | (Level, Sensitivity) => true,
+        (expected, found) => expected == found,
+    }
+}
+
+/// Find the kinds of symbol that [expr] could be passed as, along with a description of it
+/// for diagnostics. Expressions whose kind can't be determined, such as references to
+/// symbols that aren't declared in the source root, return `None`.
+fn argument_kinds(expr: &Expr, symbols: &SymbolKinds) -> Option<(Vec<KeywordKind>, String)> {
+    use secsp_syntax::ast::KeywordKind::*;
+
+    let (kinds, description) = match expr {
+        Expr::Path(path) => {
+            let segments = path.segments();
+
+            // Parameters of an enclosing macro shadow any other symbol with the same name.
+            let enclosing_param = path
+                .syntax()
+                .ancestors()
+                .filter_map(MacroDef::cast)
+                .flat_map(|macro_def| macro_def.param_list())
+                .flat_map(|list| list.params())
+                .find(|param| segments.len() == 1 && param.name().as_ref() == segments.last());
+
+            let kinds = match enclosing_param.and_then(|param| param.param_kind()) {
+                Some(kind) => vec![kind],
+                None => symbols.get(segments.last()?)?.clone(),
+            };
+
+            let description = format!("`{}` `{}`", kinds.first()?.as_ref(), path.path());
+
+            (kinds.into_iter().map(param_kind).collect(), description)
+        }
+        Expr::Cast(cast) => {
+            let kind = cast.target_kind()?;
+            let description = format!("`{}` `{}`", kind.as_ref(), expr.syntax().text());
+
+            (vec![param_kind(kind)], description)
+        }
+        Expr::Paren(paren) => return argument_kinds(&paren.expr()?, symbols),
+        Expr::Literal(literal) => {
+            let (kinds, description) = match literal.kind()? {
+                LiteralKind::String => (vec![String, Name], "a string"),
+                LiteralKind::Boolean => (vec![Bool], "a boolean"),
+                LiteralKind::Ipv4Address | LiteralKind::Ipv6Address => {
+                    (vec![IpAddress], "an IP address")
+                }
+                LiteralKind::Integer => (vec![], "an integer"),
+                LiteralKind::PathPattern => (vec![String], "a path pattern"),
+            };
+
+            (kinds, description.to_string())
+        }
+        Expr::Level(_) => (vec![Level], "a level".to_string()),
+        Expr::LevelRange(_) => (vec![LevelRange], "a level range".to_string()),
+        Expr::CategoryRange(_) | Expr::List(_) => (vec![CategorySet], "a set".to_string()),
+        Expr::Set(_) => (vec![ClassPermission], "a set of permissions".to_string()),
+        Expr::Context(_) => (vec![], "a context".to_string()),
+        Expr::Binary(_) | Expr::Prefix(_) => return None,
+    };
+
+    Some((kinds, description))
+}
+
+/// Check that each argument in [bound] is of the type of the parameter it is bound to.
+fn type_diagnostics(
+    macro_def: &MacroDef,
+    bound: &[(MacroParam, Expr)],
+    symbols: &SymbolKinds,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    for (param, value) in bound {
+        let (expected, name) = match (param.param_kind(), param.name()) {
+            (Some(kind), Some(name)) if kind.is_param_type() => (kind, name),
+            _ => continue,
+        };
+
+        let (found, description) = match argument_kinds(value, symbols) {
+            Some(found) => found,
+            None => continue,
+        };
+
+        if !found.iter().any(|kind| is_assignable(expected, *kind)) {
+            diagnostics.push(Diagnostic::new(
+                format!(
+                    "expected `{}` for parameter `{}` of macro `{}`, found {}",
+                    expected.as_ref(),
+                    name,
+                    macro_def.name().unwrap_or_default(),
+                    description
+                ),
+                value.syntax().text_range(),
+            ));
+        }
+    }
+}
+
+fn file_symbol_kinds(db: &impl MacrosDatabase, file_id: FileId) -> Arc<SymbolKinds> {
+    let file = db.source_file(file_id).tree();
+    let mut symbols = SymbolKinds::default();
+
+    for node in ast::descendants(file.syntax()) {
+        let symbol = if let Some(var) = VariableDef::cast(node.clone()) {
+            var.name().zip(var.var_kind())
+        } else if let Some(param) = MacroParam::cast(node.clone()) {
+            param.name().zip(param.param_kind())
+        } else if let Some(alias) = TypeAliasDef::cast(node.clone()) {
+            alias.name().map(|name| (name, KeywordKind::TypeAlias))
+        } else if let Some(class) = ClassDef::cast(node) {
+            class.name().map(|name| (name, KeywordKind::Class))
+        } else {
+            None
+        };
+
+        if let Some((name, kind)) = symbol {
+            symbols.entry(name).or_default().push(kind);
+        }
+    }
+
+    Arc::new(symbols)
+}
+
+fn symbol_kinds(db: &impl MacrosDatabase) -> Arc<SymbolKinds> {
+    let mut symbols = SymbolKinds::default();
+
+    for file_id in db.source_root().0.iter() {
+        for (name, kinds) in db.file_symbol_kinds(*file_id).iter() {
+            symbols
+                .entry(name.clone())
+                .or_default()
+                .extend(kinds.iter().cloned());
+        }
+    }
+
+    Arc::new(symbols)
+}
+
+fn macro_diagnostics(db: &impl MacrosDatabase, file_id: FileId) -> Arc<Vec<Diagnostic>> {
+    let file = db.source_file(file_id).tree();
+    let macros = MacroTable::new(
+        db.source_root()
+            .0
+            .iter()
+            .map(|file_id| db.source_file(*file_id).tree()),
+    );
+
+    let symbols = db.symbol_kinds();
+    let mut diagnostics = vec![];
+
+    for node in ast::descendants(file.syntax()) {
+        if let Some(macro_def) = MacroDef::cast(node.clone()) {
+            param_diagnostics(&macro_def, &mut diagnostics);
+
+            let defaults: Vec<_> = macro_def
+                .param_list()
+                .iter()
+                .flat_map(|list| list.params())
+                .filter_map(|param| param.default_value().map(|value| (param, value)))
+                .collect();
+
+            type_diagnostics(&macro_def, &defaults, &symbols, &mut diagnostics);
+        } else if let Some(call) = MacroCall::cast(node) {
+            let macro_def = match call.path().and_then(|path| macros.resolve(&path)) {
+                Some(macro_def) => macro_def,
+                None => continue,
+            };
+
+            match bind_arguments(macro_def, &call) {
+                Ok(bound) => {
+                    // Default values are checked once, where the macro is defined.
+                    let arguments: Vec<_> = bound
+                        .into_iter()
+                        .filter(|(param, value)| param.default_value().as_ref() != Some(value))
+                        .collect();
+
+                    type_diagnostics(macro_def, &arguments, &symbols, &mut diagnostics);
+                }
+                Err(errors) => diagnostics.extend(errors),
+            }
+        }
+    }
+
+    Arc::new(diagnostics)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::AnalysisHost;
+
+    fn diagnostics(text: &str) -> Vec<String> {
+        let mut host = AnalysisHost::default();
+        let file_id = host.add_file(PathBuf::from("test.csp"), text.to_string());
+
+        host.analysis()
+            .diagnostics(file_id)
+            .expect("canceled")
+            .into_iter()
+            .map(|diagnostic| diagnostic.message().to_string())
+            .collect()
+    }
+
+    const MACRO: &str = "macro m(type a, bool write = false, bool exec = false) {}";
+
+    #[test]
+    fn positional_and_named_arguments() {
+        let text = format!(
+            "{} m(t); m(t, true); m(t, exec = true); m(exec = true, a = t);",
+            MACRO
+        );
+
+        assert!(diagnostics(&text).is_empty(), "{:?}", diagnostics(&text));
+    }
+
+    #[test]
+    fn calls_to_qualified_macros() {
+        let text = r#"
+            block a { macro m(type t) {} }
+            block b { macro m() {} }
+            a.m(t);
+            b.m();
+        "#;
+
+        assert!(diagnostics(text).is_empty(), "{:?}", diagnostics(text));
+    }
+
+    #[test]
+    fn missing_argument() {
+        assert_eq!(
+            vec!["missing argument for parameter `a` of macro `m`"],
+            diagnostics(&format!("{} m(write = true);", MACRO))
+        );
+    }
+
+    #[test]
+    fn too_many_arguments() {
+        assert_eq!(
+            vec!["macro `m` takes 3 argument(s), but more were given"],
+            diagnostics(&format!("{} m(t, true, true, true);", MACRO))
+        );
+    }
+
+    #[test]
+    fn invalid_named_arguments() {
+        assert_eq!(
+            vec![
+                "argument for parameter `a` is given more than once",
+                "macro `m` has no parameter named `read`",
+                "positional argument follows a named argument",
+            ],
+            diagnostics(&format!("{} m(t, a = t, read = true, true);", MACRO))
+        );
+    }
+
+    #[test]
+    fn invalid_params() {
+        assert_eq!(
+            vec![
+                "duplicate parameter `a`",
+                "parameter `b` has no default value, but follows a parameter that does",
+            ],
+            diagnostics("macro m(type a, bool a = true, type b) {}")
+        );
+    }
+
+    #[test]
+    fn arguments_of_each_param_type() {
+        let text = r#"
+            type t;
+            type_attribute ta;
+            type_alias tb = t;
+            role r;
+            sensitivity s0;
+            category c0;
+            category c1;
+            bool b = true;
+            tunable tun = false;
+            class file (read);
+            macro m(
+                type a,
+                type_attribute b,
+                role c,
+                sensitivity d,
+                category_set e,
+                level f,
+                level_range g,
+                class h,
+                class_permission i,
+                ip_address j,
+                string k,
+                name l,
+                bool n,
+                bool o = tun
+            ) {}
+
+            m(t, tb, r, s0, c0..c1, s0, s0-s0:c0, file, file (read), 10.0.0.1, "k", "l", b);
+            m(ta, (type) u, r, s0, (c0, c1), s0:c0, s0-s0, file, file (read), ::1, "", "", true);
+        "#;
+
+        assert!(diagnostics(text).is_empty(), "{:?}", diagnostics(text));
+    }
+
+    #[test]
+    fn mismatched_argument_types() {
+        let text = r#"
+            type t;
+            role r;
+            macro m(type a, role b, ip_address c = "10.0.0.1") {}
+            block inner {
+                macro n(bool b, string s) { m(b, r); }
+            }
+            m(r, t, 10.0.0.1);
+            inner.n(t, 10.0.0.1);
+        "#;
+
+        assert_eq!(
+            vec![
+                "expected `ip_address` for parameter `c` of macro `m`, found a string",
+                "expected `type` for parameter `a` of macro `m`, found `bool` `b`",
+                "expected `type` for parameter `a` of macro `m`, found `role` `r`",
+                "expected `role` for parameter `b` of macro `m`, found `type` `t`",
+                "expected `bool` for parameter `b` of macro `n`, found `type` `t`",
+                "expected `string` for parameter `s` of macro `n`, found an IP address",
+            ],
+            diagnostics(text)
+        );
+    }
+}
@@ -0,0 +1,435 @@
+//! Validation of the levels and ranges used by multi-level security (MLS) policies against
+//! the sensitivities and categories they are declared with.
+
+use std::sync::Arc;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use secsp_syntax::ast::{
+    self, AstNode, ContextExpr, Expr, KeywordKind, LevelExpr, LevelRangeExpr, MacroParam,
+    NameOwner, OrderStmt, PathExpr, SensitivityCategoryStmt, UserLevelStmt, UserRangeStmt,
+    VariableDef,
+};
+
+use crate::diagnostics::Diagnostic;
+use crate::input::FileId;
+use crate::syntax::SyntaxDatabase;
+
+#[salsa::query_group(Mls)]
+pub trait MlsDatabase: SyntaxDatabase {
+    /// Find the MLS components declared in a single file.
+    fn file_mls_declarations(&self, file_id: FileId) -> Arc<MlsDeclarations>;
+
+    /// Find the MLS components declared across every file in the source root.
+    fn mls_declarations(&self) -> Arc<MlsDeclarations>;
+
+    /// Check the levels and ranges in a file against the declared MLS components.
+    fn mls_diagnostics(&self, file_id: FileId) -> Arc<Vec<Diagnostic>>;
+}
+
+/// The sensitivities and categories of an MLS policy, along with their aliases and ordering.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct MlsDeclarations {
+    sensitivities: MlsComponents,
+    categories: MlsComponents,
+    /// The names of level ranges and parameters that stand for a whole level or range.
+    levels: FxHashSet<String>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct MlsComponents {
+    names: FxHashSet<String>,
+    aliases: FxHashMap<String, String>,
+    order: Vec<String>,
+}
+
+impl MlsComponents {
+    fn extend(&mut self, other: &MlsComponents) {
+        self.names.extend(other.names.iter().cloned());
+        self.aliases.extend(other.aliases.clone());
+        self.order.extend(other.order.iter().cloned());
+    }
+
+    /// Find the declared name that [name] refers to, following any aliases.
+    fn resolve<'a>(&'a self, name: &'a str) -> Option<&'a str> {
+        if self.names.contains(name) {
+            Some(name)
+        } else {
+            self.aliases
+                .get(name)
+                .map(String::as_str)
+                .filter(|actual| self.names.contains(*actual))
+        }
+    }
+
+    /// Find the position of a declared name in the order statement.
+    fn rank(&self, name: &str) -> Option<usize> {
+        self.order.iter().position(|item| item == name)
+    }
+}
+
+fn file_mls_declarations(db: &impl MlsDatabase, file_id: FileId) -> Arc<MlsDeclarations> {
+    let file = db.source_file(file_id).tree();
+    let mut declarations = MlsDeclarations::default();
+
+    for node in ast::descendants(file.syntax()) {
+        if let Some(var) = VariableDef::cast(node.clone()) {
+            let name = match var.name() {
+                Some(name) => name,
+                None => continue,
+            };
+
+            let actual = || match var.initializer() {
+                Some(Expr::Path(path)) => Some(path.path()),
+                _ => None,
+            };
+
+            match var.var_kind() {
+                Some(KeywordKind::Sensitivity) => {
+                    declarations.sensitivities.names.insert(name);
+                }
+                Some(KeywordKind::Category) => {
+                    declarations.categories.names.insert(name);
+                }
+                Some(KeywordKind::LevelRange) => {
+                    declarations.levels.insert(name);
+                }
+                Some(KeywordKind::SensitivityAlias) => {
+                    if let Some(actual) = actual() {
+                        declarations.sensitivities.aliases.insert(name, actual);
+                    }
+                }
+                Some(KeywordKind::CategoryAlias) => {
+                    if let Some(actual) = actual() {
+                        declarations.categories.aliases.insert(name, actual);
+                    }
+                }
+                _ => {}
+            }
+        } else if let Some(param) = MacroParam::cast(node.clone()) {
+            // Parameters are treated as declarations, since their values are only known
+            // where the macro is called.
+            match (param.param_kind(), param.name()) {
+                (Some(KeywordKind::Sensitivity), Some(name)) => {
+                    declarations.sensitivities.names.insert(name);
+                }
+                (Some(KeywordKind::Category), Some(name)) => {
+                    declarations.categories.names.insert(name);
+                }
+                (Some(KeywordKind::Level), Some(name))
+                | (Some(KeywordKind::LevelRange), Some(name)) => {
+                    declarations.levels.insert(name);
+                }
+                _ => {}
+            }
+        } else if let Some(stmt) = OrderStmt::cast(node) {
+            let components = match stmt.order_kind() {
+                Some(KeywordKind::SensitivityOrder) => &mut declarations.sensitivities,
+                Some(KeywordKind::CategoryOrder) => &mut declarations.categories,
+                _ => continue,
+            };
+
+            let items = stmt.items().into_iter().flat_map(|items| items.items());
+
+            for item in items {
+                if let Expr::Path(path) = item {
+                    components.order.push(path.path());
+                }
+            }
+        }
+    }
+
+    Arc::new(declarations)
+}
+
+fn mls_declarations(db: &impl MlsDatabase) -> Arc<MlsDeclarations> {
+    let mut declarations = MlsDeclarations::default();
+    let mut files: Vec<FileId> = db.source_root().0.iter().cloned().collect();
+    files.sort();
+
+    for file_id in files {
+        let file_declarations = db.file_mls_declarations(file_id);
+
+        declarations
+            .sensitivities
+            .extend(&file_declarations.sensitivities);
+        declarations
+            .categories
+            .extend(&file_declarations.categories);
+        declarations
+            .levels
+            .extend(file_declarations.levels.iter().cloned());
+    }
+
+    Arc::new(declarations)
+}
+
+fn mls_diagnostics(db: &impl MlsDatabase, file_id: FileId) -> Arc<Vec<Diagnostic>> {
+    let file = db.source_file(file_id).tree();
+    let declarations = db.mls_declarations();
+    let mut validator = Validator {
+        declarations: &declarations,
+        diagnostics: vec![],
+    };
+
+    for node in ast::descendants(file.syntax()) {
+        if let Some(level) = LevelExpr::cast(node.clone()) {
+            validator.check_level(&level);
+        } else if let Some(range) = LevelRangeExpr::cast(node.clone()) {
+            validator.check_level_range(&range);
+        } else if let Some(stmt) = UserLevelStmt::cast(node.clone()) {
+            validator.check_level_path(stmt.level());
+        } else if let Some(stmt) = UserRangeStmt::cast(node.clone()) {
+            validator.check_level_path(stmt.range());
+        } else if let Some(context) = ContextExpr::cast(node.clone()) {
+            validator.check_level_path(context.level_range());
+        } else if let Some(stmt) = SensitivityCategoryStmt::cast(node) {
+            if let Some(sensitivity) = stmt.sensitivity() {
+                validator.check_sensitivity(&sensitivity);
+            }
+
+            if let Some(categories) = stmt.categories() {
+                validator.check_categories(&categories);
+            }
+        }
+    }
+
+    Arc::new(validator.diagnostics)
+}
+
+struct Validator<'a> {
+    declarations: &'a MlsDeclarations,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'a> Validator<'a> {
+    fn error<N: AstNode, S: Into<String>>(&mut self, node: &N, message: S) {
+        self.diagnostics
+            .push(Diagnostic::new(message, node.syntax().text_range()));
+    }
+
+    /// Check that [path] refers to a declared sensitivity, returning its position in the
+    /// sensitivity order if it has one.
+    fn check_sensitivity(&mut self, path: &PathExpr) -> Option<usize> {
+        let name = path.path();
+        let sensitivities = &self.declarations.sensitivities;
+
+        match sensitivities.resolve(&name) {
+            Some(actual) => sensitivities.rank(actual),
+            None => {
+                self.error(path, format!("undeclared sensitivity `{}`", name));
+                None
+            }
+        }
+    }
+
+    /// Check that [path] refers to a declared category, returning its position in the
+    /// category order if it has one.
+    fn check_category(&mut self, path: &PathExpr) -> Option<usize> {
+        let name = path.path();
+        let categories = &self.declarations.categories;
+
+        match categories.resolve(&name) {
+            Some(actual) => categories.rank(actual),
+            None => {
+                self.error(path, format!("undeclared category `{}`", name));
+                None
+            }
+        }
+    }
+
+    fn check_categories(&mut self, expr: &Expr) {
+        match expr {
+            Expr::Path(path) => {
+                self.check_category(path);
+            }
+            Expr::List(list) => {
+                for item in list.items() {
+                    self.check_categories(&item);
+                }
+            }
+            Expr::Paren(paren) => {
+                if let Some(inner) = paren.expr() {
+                    self.check_categories(&inner);
+                }
+            }
+            Expr::CategoryRange(range) => {
+                let (low, high) = match (range.low(), range.high()) {
+                    (Some(Expr::Path(low)), Some(Expr::Path(high))) => (low, high),
+                    _ => return,
+                };
+
+                let low_rank = self.check_category(&low);
+                let high_rank = self.check_category(&high);
+
+                if let (Some(low_rank), Some(high_rank)) = (low_rank, high_rank) {
+                    if low_rank > high_rank {
+                        let message = format!(
+                            "category `{}` is ordered after `{}`",
+                            low.path(),
+                            high.path()
+                        );
+
+                        self.error(range, message);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn check_level(&mut self, level: &LevelExpr) {
+        if let Some(Expr::Path(sensitivity)) = level.sensitivity() {
+            self.check_sensitivity(&sensitivity);
+        }
+
+        if let Some(categories) = level.categories() {
+            self.check_categories(&categories);
+        }
+    }
+
+    /// Check a level or range that is written as a single name, which is either a sensitivity
+    /// without categories or a named level range.
+    fn check_level_path(&mut self, expr: Option<Expr>) {
+        if let Some(Expr::Path(path)) = expr {
+            if !self.declarations.levels.contains(&path.path()) {
+                self.check_sensitivity(&path);
+            }
+        }
+    }
+
+    fn check_level_range(&mut self, range: &LevelRangeExpr) {
+        // The sensitivities of levels with categories are checked when visiting the level.
+        let mut rank = |expr: Option<Expr>| match expr {
+            Some(Expr::Path(path)) => self.check_sensitivity(&path).map(|rank| (path, rank)),
+            Some(Expr::Level(level)) => match level.sensitivity() {
+                Some(Expr::Path(path)) => {
+                    let sensitivities = &self.declarations.sensitivities;
+                    let rank = sensitivities
+                        .resolve(&path.path())
+                        .and_then(|actual| sensitivities.rank(actual));
+
+                    rank.map(|rank| (path, rank))
+                }
+                _ => None,
+            },
+            _ => None,
+        };
+
+        let low = rank(range.low());
+        let high = rank(range.high());
+
+        if let (Some((low, low_rank)), Some((high, high_rank))) = (low, high) {
+            if low_rank > high_rank {
+                let message = format!(
+                    "low sensitivity `{}` is above high sensitivity `{}`",
+                    low.path(),
+                    high.path()
+                );
+
+                self.error(range, message);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::AnalysisHost;
+
+    fn diagnostics(text: &str) -> Vec<String> {
+        let mut host = AnalysisHost::default();
+        let file_id = host.add_file(PathBuf::from("test.csp"), text.to_string());
+
+        host.analysis()
+            .diagnostics(file_id)
+            .expect("canceled")
+            .into_iter()
+            .map(|diagnostic| diagnostic.message().to_string())
+            .collect()
+    }
+
+    const DECLARATIONS: &str = r#"
+        sensitivity s0;
+        sensitivity s1;
+        sensitivity_alias high = s1;
+        sensitivity_order (s0, s1);
+        category c0;
+        category c1;
+        category_order (c0, c1);
+    "#;
+
+    #[test]
+    fn valid_levels() {
+        let text = format!(
+            "{} user_range u s0-high:c0..c1; user_level u s0:(c0 c1);",
+            DECLARATIONS
+        );
+
+        assert!(diagnostics(&text).is_empty());
+    }
+
+    #[test]
+    fn undeclared_components() {
+        let text = format!("{} user_level u s2:c2;", DECLARATIONS);
+
+        assert_eq!(
+            vec!["undeclared sensitivity `s2`", "undeclared category `c2`"],
+            diagnostics(&text)
+        );
+    }
+
+    #[test]
+    fn reversed_ranges() {
+        let text = format!("{} user_range u s1-s0:c1..c0;", DECLARATIONS);
+
+        assert_eq!(
+            vec![
+                "low sensitivity `s1` is above high sensitivity `s0`",
+                "category `c1` is ordered after `c0`"
+            ],
+            diagnostics(&text)
+        );
+    }
+
+    #[test]
+    fn undeclared_user_level_sensitivity() {
+        let text = format!("{} user_level u s9;", DECLARATIONS);
+
+        assert_eq!(vec!["undeclared sensitivity `s9`"], diagnostics(&text));
+    }
+
+    #[test]
+    fn undeclared_user_range_sensitivity() {
+        let text = format!("{} user_range u s9;", DECLARATIONS);
+
+        assert_eq!(vec!["undeclared sensitivity `s9`"], diagnostics(&text));
+    }
+
+    #[test]
+    fn undeclared_context_sensitivity() {
+        let text = format!("{} sid k; sid_context k u:r:t:s9;", DECLARATIONS);
+
+        assert_eq!(vec!["undeclared sensitivity `s9`"], diagnostics(&text));
+    }
+
+    #[test]
+    fn named_levels_are_declared() {
+        let text = format!(
+            "{} level_range r = s0-s1; user_range u r; sid_context k u:r:t:high;",
+            DECLARATIONS
+        );
+
+        assert!(diagnostics(&text).is_empty(), "{:?}", diagnostics(&text));
+    }
+
+    #[test]
+    fn macro_params_are_declared() {
+        let text =
+            "macro m(sensitivity s, category c, level l) { user_level u s:c; user_level u l; }";
+
+        assert!(diagnostics(text).is_empty());
+    }
+}
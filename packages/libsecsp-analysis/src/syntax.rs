@@ -6,9 +6,84 @@ use crate::input::{FileId, FilesDatabase};
 
 #[salsa::query_group(Syntax)]
 pub trait SyntaxDatabase: FilesDatabase + Database {
+    /// The tree of a file that was reparsed incrementally after its last edit, which is used
+    /// instead of parsing the whole of its text again.
+    #[salsa::input]
+    fn reparsed_file(&self, file_id: FileId) -> Option<Parse<ast::SourceFile>>;
+
     fn source_file(&self, file_id: FileId) -> Parse<ast::SourceFile>;
 }
 
 fn source_file(db: &impl SyntaxDatabase, file_id: FileId) -> Parse<ast::SourceFile> {
-    ast::SourceFile::parse(&*db.file_text(file_id))
+    match db.reparsed_file(file_id) {
+        Some(parse) => parse,
+        None => ast::SourceFile::parse(&*db.file_text(file_id)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use secsp_syntax::ast::SourceFile;
+    use secsp_syntax::TextEdit;
+    use text_unit::{TextRange, TextUnit};
+
+    use crate::AnalysisHost;
+
+    fn range(start: u32, end: u32) -> TextRange {
+        TextRange::from_to(TextUnit::from(start), TextUnit::from(end))
+    }
+
+    #[test]
+    fn edits_update_text_and_tree() {
+        let mut host = AnalysisHost::default();
+        let text = "block a { type t; }\nallow t t : file (read);".to_string();
+        let file_id = host.add_file(PathBuf::from("test.csp"), text);
+
+        host.edit_file(file_id, &TextEdit::replace(range(15, 16), "u".to_string()));
+        host.edit_file(file_id, &TextEdit::insert(42.into(), " write".to_string()));
+
+        let expected = "block a { type u; }\nallow t t : file (read write);";
+        let analysis = host.analysis();
+
+        assert_eq!(expected, analysis.file_text(file_id).unwrap().as_str());
+        assert_eq!(
+            SourceFile::parse(expected),
+            analysis.source_file(file_id).unwrap()
+        );
+    }
+
+    #[test]
+    fn edits_update_diagnostics() {
+        let mut host = AnalysisHost::default();
+        let text = "macro m(type t) {}\nblock a { m(b); }".to_string();
+        let file_id = host.add_file(PathBuf::from("test.csp"), text);
+
+        assert!(host.analysis().diagnostics(file_id).unwrap().is_empty());
+
+        host.edit_file(file_id, &TextEdit::delete(range(31, 32)));
+
+        let diagnostics = host.analysis().diagnostics(file_id).unwrap();
+        let messages: Vec<&str> = diagnostics.iter().map(|d| d.message()).collect();
+
+        assert_eq!(
+            vec!["missing argument for parameter `t` of macro `m`"],
+            messages
+        );
+    }
+
+    #[test]
+    fn updates_replace_edited_trees() {
+        let mut host = AnalysisHost::default();
+        let file_id = host.add_file(PathBuf::from("test.csp"), "type t;".to_string());
+
+        host.edit_file(file_id, &TextEdit::insert(6.into(), "u".to_string()));
+        host.update_file(file_id, "type v;".to_string());
+
+        assert_eq!(
+            SourceFile::parse("type v;"),
+            host.analysis().source_file(file_id).unwrap()
+        );
+    }
 }
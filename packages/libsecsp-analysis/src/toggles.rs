@@ -0,0 +1,317 @@
+//! Checks that the conditions of `if` statements only refer to declared booleans and tunables.
+
+use std::sync::Arc;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use secsp_syntax::ast::{
+    self, AstNode, ConditionalStmt, ContainerDef, KeywordKind, MacroDef, MacroParam, NameOwner,
+    PathExpr, SourceFile, VariableDef,
+};
+
+use crate::diagnostics::Diagnostic;
+use crate::input::FileId;
+use crate::syntax::SyntaxDatabase;
+
+#[salsa::query_group(Toggles)]
+pub trait TogglesDatabase: SyntaxDatabase {
+    /// Find the booleans and tunables declared in a single file.
+    fn file_toggles(&self, file_id: FileId) -> Arc<ToggleTable>;
+
+    /// Find the booleans and tunables declared across every file in the source root.
+    fn toggles(&self) -> Arc<ToggleTable>;
+
+    /// Check that the conditions in a file only refer to declared booleans and tunables.
+    fn toggle_diagnostics(&self, file_id: FileId) -> Arc<Vec<Diagnostic>>;
+}
+
+/// The booleans and tunables declared across a set of source files, which the paths in
+/// conditions can be resolved against.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ToggleTable {
+    /// The path of each boolean and tunable from the root of its file, with the kind it was
+    /// declared as.
+    declared: FxHashMap<Vec<String>, KeywordKind>,
+    /// The path of each container from the root of its file, with the paths of the containers
+    /// it extends and whether each of those paths is global.
+    containers: FxHashMap<Vec<String>, Vec<(Vec<String>, bool)>>,
+}
+
+impl ToggleTable {
+    pub fn new<I: IntoIterator<Item = SourceFile>>(files: I) -> Self {
+        let mut toggles = ToggleTable::default();
+
+        for file in files {
+            toggles.add_file(&file);
+        }
+
+        toggles
+    }
+
+    fn add_file(&mut self, file: &SourceFile) {
+        for node in ast::descendants(file.syntax()) {
+            let declared = if let Some(var) = VariableDef::cast(node.clone()) {
+                var.name()
+                    .zip(var.var_kind().filter(is_toggle))
+                    .map(|(name, kind)| (scope_of(&var), name, kind))
+            } else if let Some(param) = MacroParam::cast(node.clone()) {
+                param
+                    .name()
+                    .zip(param.param_kind().filter(is_toggle))
+                    .map(|(name, kind)| (scope_of(&param), name, kind))
+            } else if let Some(container) = ContainerDef::cast(node) {
+                let parents = container
+                    .extends_list()
+                    .into_iter()
+                    .flat_map(|list| list.paths())
+                    .map(|path| (path.segments(), path.is_global()));
+
+                self.containers
+                    .entry(scope_of(&container))
+                    .or_default()
+                    .extend(parents);
+                None
+            } else {
+                None
+            };
+
+            if let Some((mut path, name, kind)) = declared {
+                path.push(name);
+                self.declared.insert(path, kind);
+            }
+        }
+    }
+
+    fn extend(&mut self, other: &ToggleTable) {
+        self.declared.extend(
+            other
+                .declared
+                .iter()
+                .map(|(path, kind)| (path.clone(), *kind)),
+        );
+
+        for (container, parents) in &other.containers {
+            self.containers
+                .entry(container.clone())
+                .or_default()
+                .extend(parents.iter().cloned());
+        }
+    }
+
+    /// Find the kind of the boolean or tunable that [path] refers to from where it is written.
+    /// Like CIL, a path is looked up in each enclosing scope from the innermost outwards, and in
+    /// the containers that each of those scopes extend.
+    pub fn resolve(&self, path: &PathExpr) -> Option<KeywordKind> {
+        let scope = scope_of(path);
+        let segments = path.segments();
+        let outermost = if path.is_global() { 0 } else { scope.len() };
+        let mut visited = FxHashSet::default();
+
+        (0..=outermost)
+            .rev()
+            .find_map(|len| self.resolve_in(&scope[..len], &segments, &mut visited))
+    }
+
+    fn resolve_in(
+        &self,
+        scope: &[String],
+        segments: &[String],
+        visited: &mut FxHashSet<Vec<String>>,
+    ) -> Option<KeywordKind> {
+        if !visited.insert(scope.to_vec()) {
+            return None;
+        }
+
+        let mut full_path = scope.to_vec();
+        full_path.extend(segments.iter().cloned());
+
+        if let Some(kind) = self.declared.get(&full_path) {
+            return Some(*kind);
+        }
+
+        // Parents are resolved from the scope that encloses the extending container.
+        let (enclosing, parents) = match (scope.split_last(), self.containers.get(scope)) {
+            (Some((_, enclosing)), Some(parents)) => (enclosing, parents),
+            _ => return None,
+        };
+
+        parents.iter().find_map(|(parent, is_global)| {
+            let parent_scope = self.resolve_container(enclosing, parent, *is_global)?;
+            self.resolve_in(&parent_scope, segments, visited)
+        })
+    }
+
+    /// Find the full path of the container that [segments] refers to from within [scope].
+    fn resolve_container(
+        &self,
+        scope: &[String],
+        segments: &[String],
+        is_global: bool,
+    ) -> Option<Vec<String>> {
+        let outermost = if is_global { 0 } else { scope.len() };
+
+        (0..=outermost).rev().find_map(|len| {
+            let mut full_path = scope[..len].to_vec();
+            full_path.extend(segments.iter().cloned());
+
+            Some(full_path).filter(|full_path| self.containers.contains_key(full_path))
+        })
+    }
+}
+
+/// Find the names of the containers and macros that enclose [node], from the outermost inwards.
+fn scope_of<N: AstNode>(node: &N) -> Vec<String> {
+    let mut scope: Vec<String> = node
+        .syntax()
+        .ancestors()
+        .filter_map(|node| match ContainerDef::cast(node.clone()) {
+            Some(container) => container.name(),
+            None => MacroDef::cast(node).and_then(|macro_def| macro_def.name()),
+        })
+        .collect();
+
+    scope.reverse();
+    scope
+}
+
+fn is_toggle(kind: &KeywordKind) -> bool {
+    *kind == KeywordKind::Bool || *kind == KeywordKind::Tunable
+}
+
+fn file_toggles(db: &impl TogglesDatabase, file_id: FileId) -> Arc<ToggleTable> {
+    let mut toggles = ToggleTable::default();
+    toggles.add_file(&db.source_file(file_id).tree());
+
+    Arc::new(toggles)
+}
+
+fn toggles(db: &impl TogglesDatabase) -> Arc<ToggleTable> {
+    let mut toggles = ToggleTable::default();
+
+    for file_id in db.source_root().0.iter() {
+        toggles.extend(&db.file_toggles(*file_id));
+    }
+
+    Arc::new(toggles)
+}
+
+fn toggle_diagnostics(db: &impl TogglesDatabase, file_id: FileId) -> Arc<Vec<Diagnostic>> {
+    let file = db.source_file(file_id).tree();
+    let toggles = db.toggles();
+    let mut diagnostics = vec![];
+
+    let conditions = ast::descendants(file.syntax())
+        .filter_map(ConditionalStmt::cast)
+        .filter_map(|cond| cond.condition());
+
+    for condition in conditions {
+        let paths = ast::descendants(condition.syntax()).filter_map(PathExpr::cast);
+
+        for path in paths {
+            if toggles.resolve(&path).is_none() {
+                diagnostics.push(Diagnostic::new(
+                    format!("undeclared boolean or tunable `{}`", path.path()),
+                    path.syntax().text_range(),
+                ));
+            }
+        }
+    }
+
+    Arc::new(diagnostics)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use secsp_syntax::ast::{self, AstNode, ConditionalStmt, KeywordKind, PathExpr, SourceFile};
+
+    use super::ToggleTable;
+    use crate::AnalysisHost;
+
+    fn diagnostics(text: &str) -> Vec<String> {
+        let mut host = AnalysisHost::default();
+        let file_id = host.add_file(PathBuf::from("test.csp"), text.to_string());
+
+        host.analysis()
+            .diagnostics(file_id)
+            .expect("canceled")
+            .into_iter()
+            .map(|diagnostic| diagnostic.message().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn declared_toggles() {
+        let text = r#"
+            bool b = true;
+            block a {
+                tunable t = false;
+                block inner { if t && b {} }
+            }
+            if b && !a.t {} else if .a.t {}
+            macro m(bool p) { if p {} }
+            block c extends a { if t {} }
+        "#;
+
+        assert!(diagnostics(text).is_empty(), "{:?}", diagnostics(text));
+    }
+
+    #[test]
+    fn toggles_are_resolved_in_scope() {
+        let text = r#"
+            block a { bool flag = true; }
+            block b { block d {} }
+            if flag || b.flag {}
+            block c { if .c.flag {} }
+            macro m(bool p) {}
+            if p {}
+        "#;
+
+        assert_eq!(
+            vec![
+                "undeclared boolean or tunable `flag`",
+                "undeclared boolean or tunable `b.flag`",
+                "undeclared boolean or tunable `.c.flag`",
+                "undeclared boolean or tunable `p`"
+            ],
+            diagnostics(text)
+        );
+    }
+
+    #[test]
+    fn toggles_are_resolved_across_files() {
+        let files = vec![
+            SourceFile::parse("block a { tunable t = false; }").tree(),
+            SourceFile::parse("bool b = true; block c extends a { if t && b {} }").tree(),
+        ];
+        let toggles = ToggleTable::new(files.clone());
+        let condition = ast::descendants(files[1].syntax())
+            .find_map(ConditionalStmt::cast)
+            .and_then(|cond| cond.condition())
+            .expect("no condition");
+        let kinds: Vec<_> = ast::descendants(condition.syntax())
+            .filter_map(PathExpr::cast)
+            .map(|path| toggles.resolve(&path))
+            .collect();
+
+        assert_eq!(
+            vec![Some(KeywordKind::Tunable), Some(KeywordKind::Bool)],
+            kinds
+        );
+    }
+
+    #[test]
+    fn undeclared_toggle() {
+        let text = "type t; if t || u {} else if v {}";
+
+        assert_eq!(
+            vec![
+                "undeclared boolean or tunable `t`",
+                "undeclared boolean or tunable `u`",
+                "undeclared boolean or tunable `v`"
+            ],
+            diagnostics(text)
+        );
+    }
+}
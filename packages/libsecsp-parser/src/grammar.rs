@@ -3,12 +3,14 @@ use crate::syntax::SyntaxKind;
 
 pub(crate) mod atom;
 pub(crate) mod block;
+pub(crate) mod class;
 pub(crate) mod container;
 pub(crate) mod error_recovery;
 pub(crate) mod expr;
 pub(crate) mod items;
 pub(crate) mod macros;
 pub(crate) mod stmt;
+pub(crate) mod type_def;
 pub(crate) mod var;
 
 pub(super) fn root(p: &mut Parser) {
@@ -1,3 +1,4 @@
+use crate::grammar::error_recovery::{self, LIST_RECOVERY_SET};
 use crate::grammar::expr::{expression, ExprRestriction};
 use crate::parser::CompletedMarker;
 use crate::parser::Parser;
@@ -21,39 +22,55 @@ pub(crate) fn path_expr(p: &mut Parser) -> CompletedMarker {
 }
 
 pub(crate) fn list_or_paren_expr(p: &mut Parser) -> CompletedMarker {
-    assert!(p.at(TokenKind::OpenParenthesis));
+    let m = p.mark();
+    let (items, has_comma) = list_items(p);
 
+    m.complete(
+        p,
+        if items == 1 && !has_comma {
+            SyntaxKind::NODE_PAREN_EXPR
+        } else {
+            SyntaxKind::NODE_LIST_EXPR
+        },
+    )
+}
+
+/// Parse a parenthesized list of items that is never treated as a paren expression, even if
+/// it only contains a single item.
+pub(crate) fn list_expr(p: &mut Parser) -> CompletedMarker {
     let m = p.mark();
-    p.bump();
+    list_items(p);
+
+    m.complete(p, SyntaxKind::NODE_LIST_EXPR)
+}
+
+/// Parse the parenthesized items of a list, returning the number of items and whether any of
+/// them were separated by commas.
+fn list_items(p: &mut Parser) -> (usize, bool) {
+    p.expect(TokenKind::OpenParenthesis);
 
-    let mut non_empty = false;
+    let mut items = 0;
     let mut has_comma = false;
 
-    while !p.at(TokenKind::Eof) && !p.at(TokenKind::CloseParenthesis) {
-        // TODO: Validate that we're at a valid expression token.
-        non_empty = true;
+    // List items may be separated by commas or by whitespace alone, e.g. `(read, write)` or
+    // `(read write)`. A list that is never closed ends before whatever follows it.
+    while !p.at(TokenKind::CloseParenthesis) {
+        items += 1;
         expression(p, ExprRestriction::NoContext);
 
-        if !p.at(TokenKind::CloseParenthesis) {
+        if p.eat(TokenKind::Comma) {
             has_comma = true;
-            p.expect(TokenKind::Comma);
+        } else if error_recovery::is_at_recovery_point(p, LIST_RECOVERY_SET) {
+            break;
         }
     }
 
     p.expect(TokenKind::CloseParenthesis);
-    m.complete(
-        p,
-        if non_empty && !has_comma {
-            SyntaxKind::NODE_PAREN_EXPR
-        } else {
-            SyntaxKind::NODE_LIST_EXPR
-        },
-    )
+    (items, has_comma)
 }
 
 pub(crate) fn context_expr(p: &mut Parser, lhs: CompletedMarker) -> bool {
-    assert!(p.at(TokenKind::Colon));
-    p.bump();
+    p.expect(TokenKind::Colon);
 
     // The `:category` part of a level expression, or the `:role` part of a context expression.
     if !expression(p, ExprRestriction::NoContext) {
@@ -104,12 +121,43 @@ pub(crate) fn range_expr(p: &mut Parser, lhs: CompletedMarker, kind: SyntaxKind)
     successful
 }
 
+pub(crate) fn is_at_literal(p: &Parser) -> bool {
+    is_at_string(p)
+        || p.at(TokenKind::Integer)
+        || p.at(TokenKind::True)
+        || p.at(TokenKind::False)
+        || p.at(TokenKind::Ipv4Address)
+        || p.at(TokenKind::Ipv6Address)
+        || p.at(TokenKind::PathPattern)
+}
+
 pub(crate) fn literal_expr(p: &mut Parser) -> CompletedMarker {
     let m = p.mark();
-    p.expect_one_of(vec![SyntaxKind::TOK_STRING, SyntaxKind::TOK_INTEGER]);
+    p.expect_one_of(vec![
+        SyntaxKind::TOK_STRING,
+        SyntaxKind::TOK_UNTERMINATED_STRING,
+        SyntaxKind::TOK_PATH_PATTERN,
+        SyntaxKind::TOK_INTEGER,
+        SyntaxKind::TOK_TRUE,
+        SyntaxKind::TOK_FALSE,
+        SyntaxKind::TOK_IPV4_ADDRESS,
+        SyntaxKind::TOK_IPV6_ADDRESS,
+    ]);
     m.complete(p, SyntaxKind::NODE_LITERAL_EXPR)
 }
 
+/// Check if the parser is at a string literal. Unterminated strings are parsed as strings, so
+/// that the only error they produce is the one reported by the lexer.
+pub(crate) fn is_at_string(p: &Parser) -> bool {
+    p.at(TokenKind::String) || p.at(TokenKind::UnterminatedString)
+}
+
+/// Check if the parser is at a file path regular expression, which is either a string or an
+/// unquoted path pattern.
+pub(crate) fn is_at_file_path(p: &Parser) -> bool {
+    is_at_string(p) || p.at(TokenKind::PathPattern)
+}
+
 pub(crate) fn is_at_path_start(p: &Parser, offset: usize) -> bool {
     let tok: SyntaxKind = p.nth(offset);
 
@@ -26,13 +26,14 @@ pub(crate) fn parse_block(p: &mut Parser, include_braces: bool) {
             SyntaxKind::TOK_CLOSE_BRACE if include_braces => {
                 break;
             }
+            // A stray closing brace at the top level doesn't end the file.
+            SyntaxKind::TOK_CLOSE_BRACE => {
+                p.error("unexpected closing brace");
+                error_recovery::recover_from_token(p);
+            }
             _ => {
                 if !items::parse_item(p) {
                     error_recovery::recover_from_item(p);
-
-                    if p.eat(TokenKind::CloseBrace) {
-                        break;
-                    }
                 }
             }
         }
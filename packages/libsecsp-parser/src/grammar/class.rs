@@ -0,0 +1,29 @@
+use crate::grammar::atom;
+use crate::grammar::container::parse_extends_list;
+use crate::parser::Parser;
+use crate::syntax::{KeywordKind, TokenKind};
+
+pub(crate) fn parse_class(p: &mut Parser) {
+    p.expect_keyword(KeywordKind::Class);
+    p.expect(TokenKind::Name);
+
+    if p.at_text(KeywordKind::Extends) {
+        parse_extends_list(p);
+    }
+
+    // A class that inherits all of its permissions from a common may omit its own.
+    if p.at(TokenKind::OpenParenthesis) {
+        atom::list_expr(p);
+    }
+}
+
+pub(crate) fn parse_common(p: &mut Parser) {
+    p.expect_keyword(KeywordKind::Common);
+    p.expect(TokenKind::Name);
+
+    if p.at(TokenKind::OpenParenthesis) {
+        atom::list_expr(p);
+    } else {
+        p.error("expected permission list");
+    }
+}
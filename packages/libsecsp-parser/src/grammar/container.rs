@@ -29,10 +29,10 @@ pub(crate) fn parse_container(p: &mut Parser) {
     block::parse_block(p, true);
 }
 
-fn parse_extends_list(p: &mut Parser) {
+pub(crate) fn parse_extends_list(p: &mut Parser) {
     let m = p.mark();
 
-    assert!(p.eat_keyword(KeywordKind::Extends));
+    p.expect_keyword(KeywordKind::Extends);
     atom::path_expr(p);
 
     while p.eat(TokenKind::Comma) {
@@ -1,40 +1,126 @@
+use crate::grammar::items;
 use crate::parser::Parser;
 use crate::syntax::SyntaxKind;
+use crate::syntax::TokenKind;
 
+/// A set of tokens that the parser can resynchronize at after an error, in addition to the
+/// start of an item and the end of the file.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct RecoverySet(&'static [SyntaxKind]);
+
+/// Tokens that end the item being parsed.
+pub(crate) const ITEM_RECOVERY_SET: RecoverySet =
+    RecoverySet(&[SyntaxKind::TOK_SEMICOLON, SyntaxKind::TOK_CLOSE_BRACE]);
+
+/// Tokens that end an unclosed list of expressions.
+pub(crate) const LIST_RECOVERY_SET: RecoverySet = RecoverySet(&[
+    SyntaxKind::TOK_SEMICOLON,
+    SyntaxKind::TOK_OPEN_BRACE,
+    SyntaxKind::TOK_CLOSE_BRACE,
+]);
+
+/// Tokens that can follow an expression.
+pub(crate) const EXPR_RECOVERY_SET: RecoverySet = RecoverySet(&[
+    SyntaxKind::TOK_SEMICOLON,
+    SyntaxKind::TOK_OPEN_BRACE,
+    SyntaxKind::TOK_CLOSE_BRACE,
+    SyntaxKind::TOK_COMMA,
+    SyntaxKind::TOK_CLOSE_PARENTHESIS,
+]);
+
+/// Check if the parser is at a token that it can resynchronize at in the context of [set].
+pub(crate) fn is_at_recovery_point(p: &Parser, set: RecoverySet) -> bool {
+    p.at(TokenKind::Eof) || set.0.contains(&p.current()) || items::is_at_item_start(p)
+}
+
+/// Skip the rest of an item that failed to parse, up to and including its semicolon, or up to
+/// the start of the next item or the end of the enclosing block. Blocks within the item are
+/// skipped as a whole.
 pub(crate) fn recover_from_item(p: &mut Parser) {
-    let mut brace_depth = 0;
     let m = p.mark();
+    let mut brace_depth = 0;
+    let mut skipped = false;
 
     loop {
+        if brace_depth == 0 && is_at_recovery_point(p, ITEM_RECOVERY_SET) {
+            if p.at(TokenKind::Semicolon) {
+                p.bump();
+                skipped = true;
+            }
+
+            break;
+        }
+
         match p.current() {
             SyntaxKind::TOK_OPEN_BRACE => {
                 p.bump();
                 brace_depth += 1;
             }
             SyntaxKind::TOK_CLOSE_BRACE => {
+                p.bump();
                 brace_depth -= 1;
+
+                // A skipped block ends the item.
                 if brace_depth == 0 {
-                    m.complete(p, SyntaxKind::NODE_PARSE_ERROR);
-                    return;
+                    skipped = true;
+                    break;
                 }
-
-                p.bump();
-            }
-            SyntaxKind::TOK_SEMICOLON => {
-                p.bump();
-                m.complete(p, SyntaxKind::NODE_PARSE_ERROR);
-                return;
-            }
-            SyntaxKind::TOK_EOF => {
-                m.complete(p, SyntaxKind::NODE_PARSE_ERROR);
-                return;
             }
+            SyntaxKind::TOK_EOF => break,
             _ => p.bump(),
         }
+
+        skipped = true;
+    }
+
+    if skipped {
+        m.complete(p, SyntaxKind::NODE_PARSE_ERROR);
+    } else {
+        m.abandon(p);
+    }
+}
+
+/// Expect the semicolon that ends an item, skipping anything in its place up to the next point
+/// that parsing can resume at.
+pub(crate) fn expect_semicolon(p: &mut Parser) {
+    if !p.expect(TokenKind::Semicolon) {
+        recover_from_item(p);
     }
 }
 
+/// Skip the token an expression was expected at, unless it can follow the expression.
 pub(crate) fn recover_from_expr(p: &mut Parser) {
+    if !is_at_recovery_point(p, EXPR_RECOVERY_SET) {
+        recover_from_token(p);
+    }
+}
+
+/// Skip input that is nested too deeply to be parsed: the current token, and everything up to
+/// the bracket that closes it if it opens one.
+pub(crate) fn recover_from_nesting(p: &mut Parser) {
+    let m = p.mark();
+    let mut depth = 0;
+
+    loop {
+        match p.current() {
+            SyntaxKind::TOK_OPEN_PARENTHESIS | SyntaxKind::TOK_OPEN_BRACE => depth += 1,
+            SyntaxKind::TOK_CLOSE_PARENTHESIS | SyntaxKind::TOK_CLOSE_BRACE => depth -= 1,
+            SyntaxKind::TOK_EOF => break,
+            _ => {}
+        }
+
+        p.bump();
+
+        if depth <= 0 {
+            break;
+        }
+    }
+
+    m.complete(p, SyntaxKind::NODE_PARSE_ERROR);
+}
+
+/// Skip the current token, which doesn't belong anywhere in the tree.
+pub(crate) fn recover_from_token(p: &mut Parser) {
     let m = p.mark();
     p.bump();
     m.complete(p, SyntaxKind::NODE_PARSE_ERROR);
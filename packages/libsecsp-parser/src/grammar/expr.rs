@@ -1,7 +1,11 @@
+use std::str::FromStr;
+
 use crate::grammar::atom;
 use crate::grammar::error_recovery;
+use crate::grammar::items;
 use crate::parser::CompletedMarker;
 use crate::parser::Parser;
+use crate::syntax::KeywordKind;
 use crate::syntax::SyntaxKind;
 use crate::syntax::TokenKind;
 
@@ -65,23 +69,18 @@ pub(crate) fn expression(p: &mut Parser, restriction: ExprRestriction) -> bool {
     expression_prec(p, 1, restriction)
 }
 
-pub(crate) fn try_expression(
-    p: &mut Parser,
-    restriction: ExprRestriction,
-    msg: &'static str,
-) -> bool {
-    if !expression(p, restriction) {
-        p.error(msg);
-        false
-    } else {
-        true
+fn expression_lhs(p: &mut Parser) -> Option<CompletedMarker> {
+    if p.is_too_deep() {
+        p.error("expression is nested too deeply");
+        error_recovery::recover_from_nesting(p);
+        return None;
     }
-}
 
-fn expression_lhs(p: &mut Parser) -> Option<CompletedMarker> {
-    if p.at(TokenKind::String) || p.at(TokenKind::Integer) {
+    // A keyword that starts the next item, e.g. after a missing semicolon, is never part of an
+    // expression.
+    if atom::is_at_literal(p) {
         return Some(atom::literal_expr(p));
-    } else if atom::is_at_path_start(p, 0) {
+    } else if atom::is_at_path_start(p, 0) && !items::is_at_item_start(p) {
         return Some(atom::path_expr(p));
     }
 
@@ -92,16 +91,55 @@ fn expression_lhs(p: &mut Parser) -> Option<CompletedMarker> {
             expression_prec(p, 255, ExprRestriction::None);
             Some(m.complete(p, SyntaxKind::NODE_PREFIX_EXPR))
         }
-        SyntaxKind::TOK_OPEN_PARENTHESIS => {
-            Some(atom::list_or_paren_expr(p))
-        }
+        SyntaxKind::TOK_OPEN_PARENTHESIS if is_at_cast(p) => Some(cast_expr(p)),
+        SyntaxKind::TOK_OPEN_PARENTHESIS => Some(atom::list_or_paren_expr(p)),
         _ => {
+            p.error("expected expression");
             error_recovery::recover_from_expr(p);
             None
         }
     }
 }
 
+/// Check if the parser is at a parenthesized variable type followed by the start of another
+/// expression, e.g. `(type) a`, rather than a parenthesized reference to a name like `type`.
+fn is_at_cast(p: &Parser) -> bool {
+    let is_var_type = KeywordKind::from_str(p.nth_text(1))
+        .map(KeywordKind::is_var_type)
+        .unwrap_or(false);
+
+    let is_at_operand = atom::is_at_path_start(p, 3)
+        || [
+            SyntaxKind::TOK_OPEN_PARENTHESIS,
+            SyntaxKind::TOK_STRING,
+            SyntaxKind::TOK_INTEGER,
+            SyntaxKind::TOK_EXCLAMATION,
+            SyntaxKind::TOK_TILDE,
+        ]
+        .contains(&p.nth(3));
+
+    p.nth(1) == SyntaxKind::TOK_NAME
+        && is_var_type
+        && p.nth(2) == SyntaxKind::TOK_CLOSE_PARENTHESIS
+        && is_at_operand
+}
+
+fn cast_expr(p: &mut Parser) -> CompletedMarker {
+    let m = p.mark();
+
+    p.expect(TokenKind::OpenParenthesis);
+
+    match KeywordKind::from_str(p.current_text()) {
+        Ok(kw) => p.bump_as(kw),
+        Err(_) => p.error("expected variable type"),
+    }
+
+    p.expect(TokenKind::CloseParenthesis);
+    expression_prec(p, 255, ExprRestriction::NoContext);
+
+    m.complete(p, SyntaxKind::NODE_CAST_EXPR)
+}
+
 fn expression_prec(p: &mut Parser, precedence: u8, restriction: ExprRestriction) -> bool {
     let mut lhs = match expression_lhs(p) {
         Some(lhs) => lhs,
@@ -118,16 +156,18 @@ fn expression_prec(p: &mut Parser, precedence: u8, restriction: ExprRestriction)
         SyntaxKind::TOK_HYPHEN if restriction.allows_range() => {
             return atom::range_expr(p, lhs, SyntaxKind::NODE_LEVEL_RANGE_EXPR);
         }
-         SyntaxKind::TOK_OPEN_PARENTHESIS => {
-                let m = lhs.precede(p);
-                let _ = atom::list_or_paren_expr(p);
+        SyntaxKind::TOK_OPEN_PARENTHESIS => {
+            let m = lhs.precede(p);
+            let _ = atom::list_or_paren_expr(p);
 
-                m.complete(p, SyntaxKind::NODE_SET_EXPR);
-                return true;
-            }
+            m.complete(p, SyntaxKind::NODE_SET_EXPR);
+            return true;
+        }
         _ => {}
     };
 
+    // Chains of operators are parsed in this loop rather than by recursion, so only the
+    // operands that are parsed recursively count towards the nesting limit.
     loop {
         let current_op_prec = BinaryOperator::from(p.current())
             .map(|p| p.precedence())
@@ -2,10 +2,13 @@ use std::str::FromStr;
 
 use crate::grammar::atom;
 use crate::grammar::block::BlockType;
+use crate::grammar::error_recovery;
 use crate::grammar::{
+    class::{parse_class, parse_common},
     container::parse_container,
     macros::parse_macro,
     stmt::{kw_statement, statement},
+    type_def::{parse_permissive, parse_type_alias, parse_type_bounds},
     var::parse_var,
 };
 use crate::parser::Parser;
@@ -13,9 +16,30 @@ use crate::syntax::KeywordKind;
 use crate::syntax::SyntaxKind;
 use crate::syntax::TokenKind;
 
+/// Check if the parser is at a keyword that begins an item, which a parser recovering from an
+/// error can resume parsing at.
+pub(crate) fn is_at_item_start(p: &Parser) -> bool {
+    if p.at(TokenKind::IfKw) {
+        return true;
+    } else if !p.at(TokenKind::Name) {
+        return false;
+    }
+
+    match KeywordKind::from_str(p.current_text()) {
+        Ok(kw) if kw.is_var_type() => atom::is_at_path_start(p, 1),
+        Ok(KeywordKind::Extends)
+        | Ok(KeywordKind::Dom)
+        | Ok(KeywordKind::DomBy)
+        | Ok(KeywordKind::Incomp)
+        | Ok(KeywordKind::Ioctl) => false,
+        Ok(kw) => !kw.is_param_only(),
+        Err(_) => false,
+    }
+}
+
 pub(crate) fn parse_item(p: &mut Parser) -> bool {
     fn at_kw(p: &Parser) -> bool {
-        p.at(TokenKind::Name) || p.at(TokenKind::IfKw) || p.at(TokenKind::ElseKw)
+        atom::is_at_path_start(p, 0) || p.at(TokenKind::IfKw) || p.at(TokenKind::ElseKw)
     }
 
     if !at_kw(p) {
@@ -23,6 +47,12 @@ pub(crate) fn parse_item(p: &mut Parser) -> bool {
         return false;
     }
 
+    if p.is_too_deep() {
+        p.error("item is nested too deeply");
+        error_recovery::recover_from_nesting(p);
+        return false;
+    }
+
     fn do_parse_item(
         p: &mut Parser,
         ty: BlockType,
@@ -51,13 +81,43 @@ pub(crate) fn parse_item(p: &mut Parser) -> bool {
             SyntaxKind::NODE_MACRO_DEF,
             parse_macro,
         ),
+        Ok(KeywordKind::Class) => do_parse_item(
+            p,
+            BlockType::NotBlockLike,
+            SyntaxKind::NODE_CLASS_DEF,
+            parse_class,
+        ),
+        Ok(KeywordKind::Common) => do_parse_item(
+            p,
+            BlockType::NotBlockLike,
+            SyntaxKind::NODE_COMMON_DEF,
+            parse_common,
+        ),
+        Ok(KeywordKind::TypeAlias) => do_parse_item(
+            p,
+            BlockType::NotBlockLike,
+            SyntaxKind::NODE_TYPE_ALIAS_DEF,
+            parse_type_alias,
+        ),
+        Ok(KeywordKind::TypeBounds) => do_parse_item(
+            p,
+            BlockType::NotBlockLike,
+            SyntaxKind::NODE_TYPE_BOUNDS_DEF,
+            parse_type_bounds,
+        ),
+        Ok(KeywordKind::Permissive) => do_parse_item(
+            p,
+            BlockType::NotBlockLike,
+            SyntaxKind::NODE_PERMISSIVE_DEF,
+            parse_permissive,
+        ),
         Ok(kw) if kw.is_var_type() && atom::is_at_path_start(p, 1) => do_parse_item(
             p,
             BlockType::NotBlockLike,
             SyntaxKind::NODE_VARIABLE_DEF,
             parse_var,
         ),
-        Ok(kw) => {
+        Ok(kw) if !kw.is_param_only() => {
             m.abandon(p);
             return kw_statement(p, kw);
         }
@@ -70,7 +130,7 @@ pub(crate) fn parse_item(p: &mut Parser) -> bool {
     match item {
         Some((ty, kind)) => {
             if ty == BlockType::NotBlockLike {
-                p.expect(TokenKind::Semicolon);
+                error_recovery::expect_semicolon(p);
             } else {
                 p.eat(TokenKind::Semicolon);
             }
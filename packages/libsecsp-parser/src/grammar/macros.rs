@@ -1,14 +1,14 @@
 use std::str::FromStr;
 
 use crate::grammar::block;
+use crate::grammar::expr::{expression, ExprRestriction};
 use crate::parser::Parser;
 use crate::syntax::KeywordKind;
 use crate::syntax::SyntaxKind;
 use crate::syntax::TokenKind;
 
 pub(crate) fn parse_macro(p: &mut Parser) {
-    // pre-test: parser must be at a "macro" keyword.
-    assert!(p.eat_keyword(KeywordKind::Macro));
+    p.expect_keyword(KeywordKind::Macro);
     p.expect(TokenKind::Name);
 
     parse_macro_param_list(p);
@@ -32,13 +32,18 @@ fn parse_macro_param_list(p: &mut Parser) {
     m.complete(p, SyntaxKind::NODE_MACRO_PARAM_LIST);
 }
 
+/// Parse a macro parameter, with an optional default value: `type t` or `bool b = true`.
 fn parse_macro_param_list_item(p: &mut Parser) -> bool {
     let m = p.mark();
 
     match KeywordKind::from_str(p.current_text()).ok() {
-        Some(kw) => p.bump_as(kw),
+        Some(kw) if kw.is_param_type() => p.bump_as(kw),
+        Some(kw) => {
+            p.error("expected parameter type");
+            p.bump_as(kw);
+        }
         None if p.at(TokenKind::Name) => {
-            p.error("expected keyword");
+            p.error("expected parameter type");
             p.bump();
         }
         None => {
@@ -48,6 +53,11 @@ fn parse_macro_param_list_item(p: &mut Parser) -> bool {
     }
 
     p.expect(TokenKind::Name);
+
+    if p.eat(TokenKind::Equals) {
+        expression(p, ExprRestriction::None);
+    }
+
     m.complete(p, SyntaxKind::NODE_MACRO_PARAM_LIST_ITEM);
 
     p.eat(TokenKind::Comma)
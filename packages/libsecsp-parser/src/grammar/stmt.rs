@@ -1,12 +1,18 @@
 use crate::grammar::atom;
 use crate::grammar::block::parse_block;
 use crate::grammar::block::BlockType;
+use crate::grammar::error_recovery::{self, LIST_RECOVERY_SET};
 use crate::grammar::expr::{expression, ExprRestriction};
 use crate::parser::Parser;
 use crate::syntax::KeywordKind;
 use crate::syntax::SyntaxKind;
 use crate::syntax::TokenKind;
 
+mod constraint;
+mod fs_labeling;
+mod mls;
+mod net_labeling;
+mod rbac;
 mod type_enforcement;
 
 pub(crate) fn statement(p: &mut Parser) -> bool {
@@ -32,7 +38,19 @@ pub(crate) fn statement(p: &mut Parser) -> bool {
             macro_call(p);
             (BlockType::NotBlockLike, SyntaxKind::NODE_MACRO_CALL)
         }
+        SyntaxKind::TOK_PIPE_EQUALS | SyntaxKind::TOK_HYPHEN_EQUALS => {
+            set_modifier(p);
+            (BlockType::NotBlockLike, SyntaxKind::NODE_SET_MODIFIER_STMT)
+        }
         _ => {
+            p.error_expecting(
+                "expected macro call or set modifier",
+                vec![
+                    SyntaxKind::TOK_OPEN_PARENTHESIS,
+                    SyntaxKind::TOK_PIPE_EQUALS,
+                    SyntaxKind::TOK_HYPHEN_EQUALS,
+                ],
+            );
             m.complete(p, SyntaxKind::NODE_PARSE_ERROR);
             return false;
         }
@@ -48,27 +66,111 @@ pub(crate) fn kw_statement(p: &mut Parser, kind: KeywordKind) -> bool {
 
     match &kind {
         AuditAllow | DontAudit | NeverAllow | Allow => type_enforcement::te_rule(p, kind),
-        _ => unimplemented!(),
+        AllowXperm | AuditAllowXperm | DontAuditXperm | NeverAllowXperm => {
+            type_enforcement::xperm_rule(p, kind)
+        }
+        TypeTransition | TypeChange | TypeMember => type_enforcement::type_rule(p, kind),
+        RoleType | RoleAllow | RoleTransition => rbac::role_rule(p, kind),
+        UserRole | UserLevel | UserRange => rbac::user_stmt(p, kind),
+        SensitivityCategory => mls::sensitivity_category(p, kind),
+        ClassOrder | SensitivityOrder | CategoryOrder | SidOrder => order(p, kind),
+        SidContext => sid_context(p, kind),
+        Import => import(p, kind),
+        Constrain | MlsConstrain | ValidateTrans | MlsValidateTrans => {
+            constraint::constraint(p, kind)
+        }
+        FileContext => fs_labeling::file_context(p, kind),
+        FsUseXattr | FsUseTask | FsUseTrans => fs_labeling::fs_use(p, kind),
+        Genfscon => fs_labeling::genfscon(p, kind),
+        PortContext => net_labeling::port_context(p, kind),
+        NetifContext => net_labeling::netif_context(p, kind),
+        NodeContext => net_labeling::node_context(p, kind),
+        IbPkeyContext => net_labeling::ibpkey_context(p, kind),
+        IbEndportContext => net_labeling::ibendport_context(p, kind),
+        _ => {
+            p.error("expected statement");
+            false
+        }
+    }
+}
+
+/// Parse a statement that declares the order of a list of symbols, e.g. `class_order (a, b);`.
+fn order(p: &mut Parser, kind: KeywordKind) -> bool {
+    let m = p.mark();
+    p.bump_as(kind);
+
+    if p.at(TokenKind::OpenParenthesis) {
+        atom::list_expr(p);
+    } else {
+        p.error("expected list of ordered items");
     }
+
+    error_recovery::expect_semicolon(p);
+    m.complete(p, SyntaxKind::NODE_ORDER_STMT);
+    true
+}
+
+/// Parse a statement importing another source file, relative to the importing file, e.g.
+/// `import "types/base.csp";`.
+fn import(p: &mut Parser, kind: KeywordKind) -> bool {
+    let m = p.mark();
+    p.bump_as(kind);
+
+    if atom::is_at_string(p) {
+        atom::literal_expr(p);
+    } else {
+        p.error("expected path of imported file");
+    }
+
+    error_recovery::expect_semicolon(p);
+    m.complete(p, SyntaxKind::NODE_IMPORT_STMT);
+    true
+}
+
+/// Parse a statement labeling an initial security identifier, e.g.
+/// `sid_context kernel system_u:system_r:kernel_t;`.
+fn sid_context(p: &mut Parser, kind: KeywordKind) -> bool {
+    let m = p.mark();
+    p.bump_as(kind);
+
+    if !atom::is_at_path_start(p, 0) {
+        p.error("expected initial security identifier");
+        m.abandon(p);
+        return false;
+    }
+
+    atom::path_expr(p);
+    expression(p, ExprRestriction::None);
+    error_recovery::expect_semicolon(p);
+    m.complete(p, SyntaxKind::NODE_SID_CONTEXT_STMT);
+    true
 }
 
 fn finish_stmt(p: &mut Parser, block_type: BlockType) {
     if block_type == BlockType::NotBlockLike {
-        p.expect(TokenKind::Semicolon);
+        error_recovery::expect_semicolon(p);
     } else {
         p.eat(TokenKind::Semicolon);
     }
 }
 
+fn set_modifier(p: &mut Parser) {
+    p.bump();
+    expression(p, ExprRestriction::NoContext);
+}
+
 fn conditional(p: &mut Parser) {
     let m = p.mark();
-    assert!(p.eat(TokenKind::IfKw));
+    p.expect(TokenKind::IfKw);
 
     expression(p, ExprRestriction::NoContext);
     parse_block(p, true);
 
     if p.eat(TokenKind::ElseKw) {
-        if p.at(TokenKind::IfKw) {
+        if p.at(TokenKind::IfKw) && p.is_too_deep() {
+            // The rest of the chain is left to be parsed as a separate conditional.
+            p.error("conditional is nested too deeply");
+        } else if p.at(TokenKind::IfKw) {
             conditional(p);
         } else {
             parse_block(p, true);
@@ -79,15 +181,31 @@ fn conditional(p: &mut Parser) {
 }
 
 fn macro_call(p: &mut Parser) {
-    assert!(p.eat(TokenKind::OpenParenthesis));
+    let m = p.mark();
+    p.expect(TokenKind::OpenParenthesis);
 
+    // An argument list that is never closed ends before whatever follows it.
     while !p.at(TokenKind::CloseParenthesis) {
-        if !expression(p, ExprRestriction::None) {
+        macro_argument(p);
+
+        if !p.eat(TokenKind::Comma) && error_recovery::is_at_recovery_point(p, LIST_RECOVERY_SET) {
             break;
         }
-
-        p.eat(TokenKind::Comma);
     }
 
     p.expect(TokenKind::CloseParenthesis);
+    m.complete(p, SyntaxKind::NODE_MACRO_ARGUMENT_LIST);
+}
+
+/// Parse a macro argument, which is either positional or named: `a` or `name = a`.
+fn macro_argument(p: &mut Parser) {
+    let m = p.mark();
+
+    if p.at(TokenKind::Name) && p.nth(1) == SyntaxKind::TOK_EQUALS {
+        p.bump();
+        p.bump();
+    }
+
+    expression(p, ExprRestriction::None);
+    m.complete(p, SyntaxKind::NODE_MACRO_ARGUMENT_LIST_ITEM);
 }
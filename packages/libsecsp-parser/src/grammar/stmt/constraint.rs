@@ -0,0 +1,153 @@
+use std::str::FromStr;
+
+use crate::grammar::atom;
+use crate::grammar::error_recovery;
+use crate::parser::{CompletedMarker, Parser};
+use crate::syntax::{KeywordKind, SyntaxKind, TokenKind};
+
+/// Parse a statement that restricts permissions or relabeling based on the security contexts
+/// involved:
+///
+/// `constrain file (read write) u1 == u2 && r1 == r2;`
+/// `mls_constrain file (read) l1 dom h2;`
+/// `validate_trans file t1 == t2 || !(r3 == object_r);`
+/// `mls_validate_trans file l1 domby h2;`
+pub(super) fn constraint(p: &mut Parser, kind: KeywordKind) -> bool {
+    let m = p.mark();
+    p.bump_as(kind);
+
+    if !atom::is_at_path_start(p, 0) {
+        p.error("expected class");
+        m.abandon(p);
+        return false;
+    }
+
+    let class = atom::path_expr(p);
+
+    // Constraints are checked against a class and set of permissions, but a parenthesized
+    // constraint expression may directly follow the name of a class permission set.
+    let is_constraint = kind == KeywordKind::Constrain || kind == KeywordKind::MlsConstrain;
+
+    if is_constraint && p.at(TokenKind::OpenParenthesis) && !is_at_constraint_expr(p, 1) {
+        let set = class.precede(p);
+        atom::list_or_paren_expr(p);
+        set.complete(p, SyntaxKind::NODE_SET_EXPR);
+    }
+
+    constraint_expr(p, 1);
+
+    error_recovery::expect_semicolon(p);
+    m.complete(p, SyntaxKind::NODE_CONSTRAINT_STMT);
+    true
+}
+
+/// Check if the token at [offset] begins a constraint expression rather than a list of
+/// permissions.
+fn is_at_constraint_expr(p: &Parser, offset: usize) -> bool {
+    match p.nth(offset) {
+        SyntaxKind::TOK_EXCLAMATION | SyntaxKind::TOK_OPEN_PARENTHESIS => true,
+        SyntaxKind::TOK_NAME => is_at_comparison_op(p, offset + 1),
+        _ => false,
+    }
+}
+
+fn is_at_comparison_op(p: &Parser, offset: usize) -> bool {
+    match p.nth(offset) {
+        SyntaxKind::TOK_DOUBLE_EQUALS | SyntaxKind::TOK_EXCLAMATION_EQUALS => true,
+        SyntaxKind::TOK_NAME => comparison_keyword(p.nth_text(offset)).is_some(),
+        _ => false,
+    }
+}
+
+/// Get the keyword of a named comparison operator: `dom`, `domby` or `incomp`.
+fn comparison_keyword(text: &str) -> Option<KeywordKind> {
+    KeywordKind::from_str(text)
+        .ok()
+        .filter(|kw| [KeywordKind::Dom, KeywordKind::DomBy, KeywordKind::Incomp].contains(kw))
+}
+
+/// Parse a constraint expression, combining comparisons with logical operators that bind at
+/// least as tightly as [precedence].
+fn constraint_expr(p: &mut Parser, precedence: u8) -> bool {
+    let mut lhs = match constraint_expr_lhs(p) {
+        Some(lhs) => lhs,
+        None => return false,
+    };
+
+    // Chains of operators are parsed in this loop rather than by recursion, so only the
+    // operands that are parsed recursively count towards the nesting limit.
+    loop {
+        let op_precedence = match p.current() {
+            SyntaxKind::TOK_DOUBLE_PIPE => 1,
+            SyntaxKind::TOK_DOUBLE_AMPERSAND => 2,
+            _ => break,
+        };
+
+        if op_precedence < precedence {
+            break;
+        }
+
+        let m = lhs.precede(p);
+        p.bump();
+
+        constraint_expr(p, op_precedence + 1);
+        lhs = m.complete(p, SyntaxKind::NODE_CONSTRAINT_BINARY_EXPR);
+    }
+
+    true
+}
+
+fn constraint_expr_lhs(p: &mut Parser) -> Option<CompletedMarker> {
+    if p.is_too_deep() {
+        p.error("constraint expression is nested too deeply");
+        error_recovery::recover_from_nesting(p);
+        return None;
+    }
+
+    match p.current() {
+        SyntaxKind::TOK_EXCLAMATION => {
+            let m = p.mark();
+            p.bump();
+            constraint_expr(p, 255);
+            Some(m.complete(p, SyntaxKind::NODE_CONSTRAINT_NOT_EXPR))
+        }
+        SyntaxKind::TOK_OPEN_PARENTHESIS => {
+            let m = p.mark();
+            p.bump();
+            constraint_expr(p, 1);
+            p.expect(TokenKind::CloseParenthesis);
+            Some(m.complete(p, SyntaxKind::NODE_CONSTRAINT_PAREN_EXPR))
+        }
+        SyntaxKind::TOK_NAME => Some(comparison_expr(p)),
+        _ => {
+            p.error("expected constraint expression");
+            error_recovery::recover_from_expr(p);
+            None
+        }
+    }
+}
+
+/// Parse a comparison between a part of a security context and another part or a set of
+/// names, e.g. `t1 == t2` or `r1 != (system_r staff_r)`.
+fn comparison_expr(p: &mut Parser) -> CompletedMarker {
+    let m = p.mark();
+    atom::path_expr(p);
+
+    match p.current() {
+        SyntaxKind::TOK_DOUBLE_EQUALS | SyntaxKind::TOK_EXCLAMATION_EQUALS => p.bump(),
+        _ => match comparison_keyword(p.current_text()) {
+            Some(kw) => p.bump_as(kw),
+            None => p.error("expected comparison operator"),
+        },
+    }
+
+    if p.at(TokenKind::OpenParenthesis) {
+        atom::list_expr(p);
+    } else if atom::is_at_path_start(p, 0) {
+        atom::path_expr(p);
+    } else {
+        p.error("expected name or list of names");
+    }
+
+    m.complete(p, SyntaxKind::NODE_CONSTRAINT_COMPARISON_EXPR)
+}
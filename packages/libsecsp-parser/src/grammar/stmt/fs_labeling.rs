@@ -0,0 +1,83 @@
+use crate::grammar::atom;
+use crate::grammar::error_recovery;
+use crate::grammar::expr;
+use crate::grammar::expr::ExprRestriction;
+use crate::parser::Parser;
+use crate::syntax::{KeywordKind, SyntaxKind};
+
+/// Parse a statement labeling the files that match a path regular expression, optionally
+/// restricted to a single type of file:
+///
+/// `file_context "/usr/bin(/.*)?" system_u:object_r:bin_t;`
+/// `file_context /usr/lib/.*\.so system_u:object_r:lib_t;`
+/// `file_context "/tmp" dir system_u:object_r:tmp_t;`
+pub(super) fn file_context(p: &mut Parser, kind: KeywordKind) -> bool {
+    let m = p.mark();
+    p.bump_as(kind);
+
+    if !atom::is_at_file_path(p) {
+        p.error("expected path regular expression");
+        m.abandon(p);
+        return false;
+    }
+
+    atom::literal_expr(p);
+
+    // A name that isn't the start of a context is the type of file being labeled.
+    if atom::is_at_path_start(p, 0) && p.nth(1) != SyntaxKind::TOK_COLON {
+        atom::path_expr(p);
+    }
+
+    expr::expression(p, ExprRestriction::None);
+    error_recovery::expect_semicolon(p);
+    m.complete(p, SyntaxKind::NODE_FILE_CONTEXT_STMT);
+    true
+}
+
+/// Parse a statement setting how the objects of a filesystem are labeled:
+///
+/// `fs_use_xattr ext4 system_u:object_r:fs_t;`
+/// `fs_use_task pipefs system_u:object_r:fs_t;`
+/// `fs_use_trans tmpfs system_u:object_r:tmpfs_t;`
+pub(super) fn fs_use(p: &mut Parser, kind: KeywordKind) -> bool {
+    let m = p.mark();
+    p.bump_as(kind);
+
+    if !atom::is_at_path_start(p, 0) {
+        p.error("expected filesystem type");
+        m.abandon(p);
+        return false;
+    }
+
+    atom::path_expr(p);
+    expr::expression(p, ExprRestriction::None);
+    error_recovery::expect_semicolon(p);
+    m.complete(p, SyntaxKind::NODE_FS_USE_STMT);
+    true
+}
+
+/// Parse a statement labeling the files of a filesystem that doesn't support extended
+/// attributes, e.g. `genfscon proc "/" system_u:object_r:proc_t;`.
+pub(super) fn genfscon(p: &mut Parser, kind: KeywordKind) -> bool {
+    let m = p.mark();
+    p.bump_as(kind);
+
+    if !atom::is_at_path_start(p, 0) {
+        p.error("expected filesystem type");
+        m.abandon(p);
+        return false;
+    }
+
+    atom::path_expr(p);
+
+    if atom::is_at_file_path(p) {
+        atom::literal_expr(p);
+    } else {
+        p.error("expected path");
+    }
+
+    expr::expression(p, ExprRestriction::None);
+    error_recovery::expect_semicolon(p);
+    m.complete(p, SyntaxKind::NODE_GENFSCON_STMT);
+    true
+}
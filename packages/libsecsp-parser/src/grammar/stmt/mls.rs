@@ -0,0 +1,26 @@
+use crate::grammar::atom;
+use crate::grammar::error_recovery;
+use crate::grammar::expr;
+use crate::grammar::expr::ExprRestriction;
+use crate::parser::Parser;
+use crate::syntax::{KeywordKind, SyntaxKind};
+
+/// Parse a statement associating a set of categories with a sensitivity, e.g.
+/// `sensitivity_category s0 c0..c255;`.
+pub(super) fn sensitivity_category(p: &mut Parser, kind: KeywordKind) -> bool {
+    let m = p.mark();
+    p.bump_as(kind);
+
+    if !atom::is_at_path_start(p, 0) {
+        p.error("expected sensitivity");
+        m.abandon(p);
+        return false;
+    }
+
+    atom::path_expr(p);
+    expr::expression(p, ExprRestriction::NoContext);
+
+    error_recovery::expect_semicolon(p);
+    m.complete(p, SyntaxKind::NODE_SENSITIVITY_CATEGORY_STMT);
+    true
+}
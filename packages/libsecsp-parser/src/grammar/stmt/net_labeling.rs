@@ -0,0 +1,132 @@
+use crate::grammar::atom;
+use crate::grammar::error_recovery;
+use crate::grammar::expr;
+use crate::grammar::expr::ExprRestriction;
+use crate::parser::Parser;
+use crate::syntax::{KeywordKind, SyntaxKind, TokenKind};
+
+/// Parse a statement labeling a port or range of ports of a network protocol:
+///
+/// `port_context tcp 80 system_u:object_r:http_port_t;`
+/// `port_context udp 8000-8080 system_u:object_r:app_port_t;`
+pub(super) fn port_context(p: &mut Parser, kind: KeywordKind) -> bool {
+    let m = p.mark();
+    p.bump_as(kind);
+
+    if !atom::is_at_path_start(p, 0) {
+        p.error("expected protocol");
+        m.abandon(p);
+        return false;
+    }
+
+    atom::path_expr(p);
+    integer_range(p, "port");
+    context_and_semicolon(p);
+    m.complete(p, SyntaxKind::NODE_PORT_CONTEXT_STMT);
+    true
+}
+
+/// Parse a statement labeling a network interface and the packets sent through it, e.g.
+/// `netif_context eth0 system_u:object_r:netif_t system_u:object_r:packet_t;`.
+pub(super) fn netif_context(p: &mut Parser, kind: KeywordKind) -> bool {
+    let m = p.mark();
+    p.bump_as(kind);
+
+    if !atom::is_at_path_start(p, 0) {
+        p.error("expected network interface");
+        m.abandon(p);
+        return false;
+    }
+
+    atom::path_expr(p);
+    expr::expression(p, ExprRestriction::None);
+    context_and_semicolon(p);
+    m.complete(p, SyntaxKind::NODE_NETIF_CONTEXT_STMT);
+    true
+}
+
+/// Parse a statement labeling the network nodes within an address and mask, e.g.
+/// `node_context 192.168.0.0 255.255.255.0 system_u:object_r:node_t;`.
+pub(super) fn node_context(p: &mut Parser, kind: KeywordKind) -> bool {
+    let m = p.mark();
+    p.bump_as(kind);
+
+    for what in &["address", "mask"] {
+        if p.at(TokenKind::Ipv4Address) || p.at(TokenKind::Ipv6Address) {
+            atom::literal_expr(p);
+        } else {
+            p.error(format!("expected IP {}", what));
+        }
+    }
+
+    context_and_semicolon(p);
+    m.complete(p, SyntaxKind::NODE_NODE_CONTEXT_STMT);
+    true
+}
+
+/// Parse a statement labeling a range of InfiniBand partition keys within a subnet, e.g.
+/// `ibpkey_context fe80:: 0x8000-0xffff system_u:object_r:pkey_t;`.
+pub(super) fn ibpkey_context(p: &mut Parser, kind: KeywordKind) -> bool {
+    let m = p.mark();
+    p.bump_as(kind);
+
+    if p.at(TokenKind::Ipv6Address) {
+        atom::literal_expr(p);
+    } else {
+        p.error("expected subnet prefix");
+    }
+
+    integer_range(p, "partition key");
+    context_and_semicolon(p);
+    m.complete(p, SyntaxKind::NODE_IBPKEY_CONTEXT_STMT);
+    true
+}
+
+/// Parse a statement labeling a port of an InfiniBand device, e.g.
+/// `ibendport_context mlx4_0 1 system_u:object_r:endport_t;`.
+pub(super) fn ibendport_context(p: &mut Parser, kind: KeywordKind) -> bool {
+    let m = p.mark();
+    p.bump_as(kind);
+
+    if !atom::is_at_path_start(p, 0) {
+        p.error("expected device name");
+        m.abandon(p);
+        return false;
+    }
+
+    atom::path_expr(p);
+
+    if p.at(TokenKind::Integer) {
+        atom::literal_expr(p);
+    } else {
+        p.error("expected port");
+    }
+
+    context_and_semicolon(p);
+    m.complete(p, SyntaxKind::NODE_IBENDPORT_CONTEXT_STMT);
+    true
+}
+
+/// Parse a single integer, or a `low-high` range of integers.
+fn integer_range(p: &mut Parser, what: &str) {
+    if !p.at(TokenKind::Integer) {
+        p.error(format!("expected {}", what));
+        return;
+    }
+
+    atom::literal_expr(p);
+
+    if p.eat(TokenKind::Hyphen) {
+        if p.at(TokenKind::Integer) {
+            atom::literal_expr(p);
+        } else {
+            p.error(format!("expected upper bound of {} range", what));
+        }
+    }
+}
+
+/// Parse the context applied by a labeling statement, followed by the end of the statement.
+fn context_and_semicolon(p: &mut Parser) {
+    expr::expression(p, ExprRestriction::None);
+    error_recovery::expect_semicolon(p);
+}
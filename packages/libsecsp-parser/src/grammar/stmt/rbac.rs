@@ -0,0 +1,79 @@
+use crate::grammar::atom;
+use crate::grammar::error_recovery;
+use crate::grammar::expr;
+use crate::grammar::expr::ExprRestriction;
+use crate::parser::Parser;
+use crate::syntax::{KeywordKind, SyntaxKind, TokenKind};
+
+/// Parse a role-based access control rule:
+///
+/// `role_type role types;`
+/// `role_allow source_role target_role;`
+/// `role_transition source_role target_type : class result_role;`
+pub(super) fn role_rule(p: &mut Parser, kind: KeywordKind) -> bool {
+    let m = p.mark();
+    p.bump_as(kind);
+
+    // The leading role is always a name, so a parenthesized list of types that follows it
+    // isn't mistaken for a subset of the role.
+    if !atom::is_at_path_start(p, 0) {
+        p.error("expected role");
+        m.abandon(p);
+        return false;
+    }
+
+    atom::path_expr(p);
+
+    if !expr::expression(p, ExprRestriction::NoContext) {
+        m.abandon(p);
+        return false;
+    }
+
+    let node_kind = match kind {
+        KeywordKind::RoleType => SyntaxKind::NODE_ROLE_TYPE_RULE,
+        KeywordKind::RoleAllow => SyntaxKind::NODE_ROLE_ALLOW_RULE,
+        KeywordKind::RoleTransition => {
+            // Parse the class of object and the resulting role.
+            p.expect(TokenKind::Colon);
+            expr::expression(p, ExprRestriction::NoContext);
+            expr::expression(p, ExprRestriction::NoContext);
+
+            SyntaxKind::NODE_ROLE_TRANSITION_RULE
+        }
+        _ => unreachable!("not a role rule keyword: {:?}", kind),
+    };
+
+    error_recovery::expect_semicolon(p);
+    m.complete(p, node_kind);
+    true
+}
+
+/// Parse a statement that attaches roles or security levels to a user:
+///
+/// `user_role user roles;`
+/// `user_level user s0;`
+/// `user_range user s0-s1:c0..c5;`
+pub(super) fn user_stmt(p: &mut Parser, kind: KeywordKind) -> bool {
+    let m = p.mark();
+    p.bump_as(kind);
+
+    if !atom::is_at_path_start(p, 0) {
+        p.error("expected user");
+        m.abandon(p);
+        return false;
+    }
+
+    atom::path_expr(p);
+
+    let (restriction, node_kind) = match kind {
+        KeywordKind::UserRole => (ExprRestriction::NoContext, SyntaxKind::NODE_USER_ROLE_STMT),
+        KeywordKind::UserLevel => (ExprRestriction::NoRange, SyntaxKind::NODE_USER_LEVEL_STMT),
+        KeywordKind::UserRange => (ExprRestriction::None, SyntaxKind::NODE_USER_RANGE_STMT),
+        _ => unreachable!("not a user statement keyword: {:?}", kind),
+    };
+
+    expr::expression(p, restriction);
+    error_recovery::expect_semicolon(p);
+    m.complete(p, node_kind);
+    true
+}
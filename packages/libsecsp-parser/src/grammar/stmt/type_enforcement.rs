@@ -1,3 +1,5 @@
+use crate::grammar::atom;
+use crate::grammar::error_recovery;
 use crate::grammar::expr;
 use crate::grammar::expr::ExprRestriction;
 use crate::parser::Parser;
@@ -8,21 +10,13 @@ pub(super) fn te_rule(p: &mut Parser, kind: KeywordKind) -> bool {
     p.bump_as(kind);
 
     // Parse the source ID
-    if !expr::try_expression(
-        p,
-        ExprRestriction::NoContext,
-        "expected identifier or type expression",
-    ) {
+    if !expr::expression(p, ExprRestriction::NoContext) {
         m.abandon(p);
         return false;
     }
 
     // Parse the target ID.
-    if !expr::try_expression(
-        p,
-        ExprRestriction::NoContext,
-        "expected identifier or type expression",
-    ) {
+    if !expr::expression(p, ExprRestriction::NoContext) {
         m.abandon(p);
         return false;
     }
@@ -32,7 +26,107 @@ pub(super) fn te_rule(p: &mut Parser, kind: KeywordKind) -> bool {
 
     if !expr::expression(p, ExprRestriction::AccessVector) {}
 
-    p.expect(TokenKind::Semicolon);
+    error_recovery::expect_semicolon(p);
     m.complete(p, SyntaxKind::NODE_TE_RULE);
     true
 }
+
+/// Parse an extended permission rule, with a single number or range of numbers or a list of
+/// them:
+///
+/// `allow_xperm src tgt : tcp_socket ioctl 0x8900;`
+/// `allow_xperm src tgt : tcp_socket ioctl (0x8900, 0x8910-0x891f);`
+pub(super) fn xperm_rule(p: &mut Parser, kind: KeywordKind) -> bool {
+    let m = p.mark();
+    p.bump_as(kind);
+
+    // Parse the source and target IDs.
+    if !expr::expression(p, ExprRestriction::NoContext)
+        || !expr::expression(p, ExprRestriction::NoContext)
+    {
+        m.abandon(p);
+        return false;
+    }
+
+    // Parse the class of object the rule applies to.
+    p.expect(TokenKind::Colon);
+    expr::expression(p, ExprRestriction::NoContext);
+
+    if !p.eat_keyword(KeywordKind::Ioctl) {
+        p.error("expected extended permission kind");
+    }
+
+    if p.eat(TokenKind::OpenParenthesis) {
+        while !p.at(TokenKind::CloseParenthesis) && xperm_range(p) {
+            p.eat(TokenKind::Comma);
+        }
+
+        p.expect(TokenKind::CloseParenthesis);
+    } else {
+        xperm_range(p);
+    }
+
+    error_recovery::expect_semicolon(p);
+    m.complete(p, SyntaxKind::NODE_XPERM_RULE);
+    true
+}
+
+/// Parse a single number or `low-high` range of numbers in an extended permission rule.
+fn xperm_range(p: &mut Parser) -> bool {
+    if !p.at(TokenKind::Integer) {
+        p.error("expected number or range of numbers");
+        return false;
+    }
+
+    let m = p.mark();
+    atom::literal_expr(p);
+
+    if p.eat(TokenKind::Hyphen) {
+        if p.at(TokenKind::Integer) {
+            atom::literal_expr(p);
+        } else {
+            p.error("expected upper bound of range");
+        }
+    }
+
+    m.complete(p, SyntaxKind::NODE_XPERM_RANGE);
+    true
+}
+
+/// Parse a rule that computes a new type for a `source` and `target` pair, e.g.
+/// `type_transition src tgt : class "object_name" result;`.
+pub(super) fn type_rule(p: &mut Parser, kind: KeywordKind) -> bool {
+    let m = p.mark();
+    p.bump_as(kind);
+
+    // Parse the source and target IDs.
+    if !expr::expression(p, ExprRestriction::NoContext)
+        || !expr::expression(p, ExprRestriction::NoContext)
+    {
+        m.abandon(p);
+        return false;
+    }
+
+    // Parse the class of object the rule applies to.
+    p.expect(TokenKind::Colon);
+    expr::expression(p, ExprRestriction::NoContext);
+
+    // Type transitions may be restricted to objects with a specific name.
+    if kind == KeywordKind::TypeTransition && atom::is_at_string(p) {
+        atom::literal_expr(p);
+    }
+
+    // Parse the resulting type.
+    expr::expression(p, ExprRestriction::NoContext);
+
+    let node_kind = match kind {
+        KeywordKind::TypeTransition => SyntaxKind::NODE_TYPE_TRANSITION_RULE,
+        KeywordKind::TypeChange => SyntaxKind::NODE_TYPE_CHANGE_RULE,
+        KeywordKind::TypeMember => SyntaxKind::NODE_TYPE_MEMBER_RULE,
+        _ => unreachable!("not a type rule keyword: {:?}", kind),
+    };
+
+    error_recovery::expect_semicolon(p);
+    m.complete(p, node_kind);
+    true
+}
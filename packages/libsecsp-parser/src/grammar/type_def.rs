@@ -0,0 +1,36 @@
+use crate::grammar::atom;
+use crate::parser::Parser;
+use crate::syntax::{KeywordKind, TokenKind};
+
+/// Parse an alternative name for a type, e.g. `type_alias unconfined = unconfined_t;`.
+pub(crate) fn parse_type_alias(p: &mut Parser) {
+    p.expect_keyword(KeywordKind::TypeAlias);
+    p.expect(TokenKind::Name);
+    p.expect(TokenKind::Equals);
+    expect_type(p);
+}
+
+/// Parse a declaration bounding a type by its parent, e.g. `type_bounds httpd_t httpd_child_t;`.
+pub(crate) fn parse_type_bounds(p: &mut Parser) {
+    p.expect_keyword(KeywordKind::TypeBounds);
+
+    if expect_type(p) {
+        expect_type(p);
+    }
+}
+
+/// Parse a declaration of a permissive domain, e.g. `permissive httpd_t;`.
+pub(crate) fn parse_permissive(p: &mut Parser) {
+    p.expect_keyword(KeywordKind::Permissive);
+    expect_type(p);
+}
+
+fn expect_type(p: &mut Parser) -> bool {
+    if atom::is_at_path_start(p, 0) {
+        atom::path_expr(p);
+        true
+    } else {
+        p.error("expected type");
+        false
+    }
+}
@@ -1,4 +1,9 @@
 extern crate rowan;
+extern crate text_unit;
+
+use std::fmt;
+
+use text_unit::{TextRange, TextUnit};
 
 use crate::parser::event;
 use crate::parser::Parser;
@@ -8,8 +13,59 @@ mod grammar;
 mod parser;
 pub mod syntax;
 
+/// An error encountered by the parser, describing what was expected at the point of failure
+/// and the token that was found instead.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ParseError(String);
+pub struct ParseError {
+    message: String,
+    expected: Vec<SyntaxKind>,
+    found: SyntaxKind,
+    range: TextRange,
+}
+
+impl ParseError {
+    /// Create a new error with an empty range.  The range is filled in by the [TreeSink]
+    /// that knows where the `found` token occurs in the source text.
+    pub fn new<S: Into<String>>(message: S, expected: Vec<SyntaxKind>, found: SyntaxKind) -> Self {
+        ParseError {
+            message: message.into(),
+            expected,
+            found,
+            range: TextRange::offset_len(TextUnit::from(0), TextUnit::from(0)),
+        }
+    }
+
+    /// Get a copy of this error positioned at the given [range].
+    pub fn with_range(self, range: TextRange) -> Self {
+        ParseError { range, ..self }
+    }
+
+    /// A human readable description of the error.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The set of token types the parser would have accepted at the point of failure.
+    pub fn expected(&self) -> &[SyntaxKind] {
+        &self.expected
+    }
+
+    /// The type of the token that the parser found instead of what it expected.
+    pub fn found(&self) -> SyntaxKind {
+        self.found
+    }
+
+    /// The range of the `found` token within the source text.
+    pub fn range(&self) -> TextRange {
+        self.range
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}, found {:?}", self.message, self.found)
+    }
+}
 
 pub trait TokenSource {
     fn kind(&self, idx: usize) -> SyntaxKind;
@@ -27,11 +83,10 @@ pub trait TreeSink {
     fn token(&mut self, ty: SyntaxKind);
 }
 
-fn parse_with<P>(source: &dyn TokenSource, sink: &mut dyn TreeSink, parse_fn: P)
+fn parse_with<P>(mut parser: Parser, sink: &mut dyn TreeSink, parse_fn: P)
 where
     P: FnOnce(&mut Parser),
 {
-    let mut parser = Parser::new(source);
     parse_fn(&mut parser);
 
     let events = parser.finish();
@@ -39,5 +94,13 @@ where
 }
 
 pub fn parse_file(source: &dyn TokenSource, sink: &mut dyn TreeSink) {
-    parse_with(source, sink, grammar::root)
+    parse_with(Parser::new(source), sink, grammar::root)
+}
+
+/// Parse a single block delimited by braces, such as the body of a container or macro, that is
+/// nested within [depth] other nodes of a tree.
+pub fn parse_block(source: &dyn TokenSource, sink: &mut dyn TreeSink, depth: usize) {
+    parse_with(Parser::nested(source, depth), sink, |p| {
+        grammar::block::parse_block(p, true)
+    })
 }
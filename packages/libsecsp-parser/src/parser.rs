@@ -2,23 +2,36 @@ pub(crate) use marker::CompletedMarker;
 
 use crate::parser::event::Event;
 use crate::syntax::{SyntaxKind, TokenKind};
-use crate::TokenSource;
+use crate::{ParseError, TokenSource};
 
 pub(crate) mod event;
 mod marker;
 
+/// The deepest that nodes can be nested within each other, so that deeply nested input can't
+/// overflow the stack of the parser or of anything walking the tree that it builds.
+const MAX_DEPTH: usize = 128;
+
 pub(crate) struct Parser<'t> {
     token_source: &'t dyn TokenSource,
     token_pos: usize,
     events: Vec<Event>,
+    last_error_pos: Option<usize>,
+    depth: usize,
 }
 
 impl<'t> Parser<'t> {
     pub(super) fn new(token_source: &'t dyn TokenSource) -> Self {
+        Parser::nested(token_source, 0)
+    }
+
+    /// Create a parser for input that is nested within [depth] other nodes of a tree.
+    pub(super) fn nested(token_source: &'t dyn TokenSource, depth: usize) -> Self {
         Parser {
             token_source,
             token_pos: 0,
             events: Vec::new(),
+            last_error_pos: None,
+            depth,
         }
     }
 
@@ -43,6 +56,21 @@ impl<'t> Parser<'t> {
         at_kw
     }
 
+    /// Check if the parser is currently positioned at the keyword [kw], remapping and consuming
+    /// it, and emitting an error if the current token is anything else.
+    pub fn expect_keyword<K>(&mut self, kw: K) -> bool
+    where
+        K: AsRef<str> + Into<SyntaxKind>,
+    {
+        if self.at_text(&kw) {
+            self.bump_as(kw.into());
+            return true;
+        }
+
+        self.error(format!("expected {}", kw.as_ref()));
+        false
+    }
+
     /// Check if the parser is currently positioned at the expected node with
     /// matching text.
     pub fn at_text<S>(&self, text: S) -> bool
@@ -93,18 +121,41 @@ impl<'t> Parser<'t> {
 
     /// Notify the parser that an error occurred at the given position with [text] as the error
     /// message.
-    pub fn error<S>(&mut self, _text: S)
+    pub fn error<S>(&mut self, text: S)
     where
-        S: AsRef<str>,
+        S: Into<String>,
     {
+        self.error_expecting(text, vec![]);
+    }
+
+    /// Notify the parser that an error occurred at the given position because the current token
+    /// was not one of the [expected] token types. Only the first error at each token is kept, as
+    /// any others are usually caused by it.
+    pub fn error_expecting<S>(&mut self, text: S, expected: Vec<SyntaxKind>)
+    where
+        S: Into<String>,
+    {
+        if self.last_error_pos == Some(self.token_pos) {
+            return;
+        }
+
+        self.last_error_pos = Some(self.token_pos);
+        let error = ParseError::new(text, expected, self.current());
+        self.events.push(Event::Error(error));
     }
 
     /// Check if the parser is currently positioned at the [expected] type, consuming it and
     /// emitting an error if the current token doesn't match what is expected.
-    pub fn expect(&mut self, expected: TokenKind) {
-        if !self.eat(expected) {
-            self.error(format!("expected {:#?}", expected));
+    pub fn expect(&mut self, expected: TokenKind) -> bool {
+        if self.eat(expected) {
+            return true;
         }
+
+        self.error_expecting(
+            format!("expected {:?}", expected),
+            vec![expected.syntax_kind()],
+        );
+        false
     }
 
     /// Check if the parser is currently positioned at a token type that matches
@@ -113,15 +164,23 @@ impl<'t> Parser<'t> {
     pub fn expect_one_of(&mut self, items: Vec<SyntaxKind>) {
         let current_kind = self.nth_kind(0);
 
-        if items.iter().any(|k| *k == current_kind) {
+        if items.contains(&current_kind) {
             self.bump();
         } else {
-            self.error("expected one of (todo)".to_string());
+            let message = format!("expected one of {:?}", items);
+            self.error_expecting(message, items);
         }
     }
 
+    /// Check if the nodes currently being parsed are nested as deeply as they can be, so any
+    /// further nesting has to be skipped.
+    pub fn is_too_deep(&self) -> bool {
+        self.depth >= MAX_DEPTH
+    }
+
     /// Create a new empty marker at the parsers current position.
     pub fn mark(&mut self) -> marker::Marker {
+        self.depth += 1;
         self.events.push(Event::BeginMarker);
         marker::Marker::new(self.events.len() - 1)
     }
@@ -29,6 +29,7 @@ impl Marker {
             parser.events.pop();
         }
 
+        parser.depth -= 1;
         self.bomb.defuse()
     }
 
@@ -39,6 +40,7 @@ impl Marker {
         };
 
         parser.events.push(Event::End);
+        parser.depth -= 1;
         self.bomb.defuse();
 
         CompletedMarker::new(kind, self.pos)
@@ -0,0 +1,1004 @@
+//! Generated by `secsp-codegen` from `libsecsp-parser/grammar.toml`, don't edit it by hand.
+//! Run `cargo run -p secsp-codegen` to regenerate it instead.
+
+use std::str::FromStr;
+
+use logos::Logos;
+
+use super::{block_comment, string};
+
+#[repr(u16)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SyntaxKind {
+    KW_ABSTRACT,
+    KW_ALLOW,
+    KW_ALLOW_XPERM,
+    KW_AUDIT_ALLOW,
+    KW_AUDIT_ALLOW_XPERM,
+    KW_BLOCK,
+    KW_BOOL,
+    KW_CATEGORY,
+    KW_CATEGORY_ALIAS,
+    KW_CATEGORY_ORDER,
+    KW_CATEGORY_SET,
+    KW_CLASS,
+    KW_CLASS_ORDER,
+    KW_CLASS_PERMISSION,
+    KW_COMMON,
+    KW_CONSTRAIN,
+    KW_DOM,
+    KW_DOMBY,
+    KW_DONT_AUDIT,
+    KW_DONT_AUDIT_XPERM,
+    KW_EXTENDS,
+    KW_FILE_CONTEXT,
+    KW_FS_USE_TASK,
+    KW_FS_USE_TRANS,
+    KW_FS_USE_XATTR,
+    KW_GENFSCON,
+    KW_IBENDPORT_CONTEXT,
+    KW_IBPKEY_CONTEXT,
+    KW_IMPORT,
+    KW_IN,
+    KW_INCOMP,
+    KW_IOCTL,
+    KW_IP_ADDRESS,
+    KW_LEVEL,
+    KW_LEVEL_RANGE,
+    KW_MACRO,
+    KW_MLS_CONSTRAIN,
+    KW_MLS_VALIDATE_TRANS,
+    KW_NAME,
+    KW_NETIF_CONTEXT,
+    KW_NEVER_ALLOW,
+    KW_NEVER_ALLOW_XPERM,
+    KW_NODE_CONTEXT,
+    KW_OPTIONAL,
+    KW_PERMISSIVE,
+    KW_PORT_CONTEXT,
+    KW_ROLE,
+    KW_ROLE_ALLOW,
+    KW_ROLE_ATTRIBUTE,
+    KW_ROLE_TRANSITION,
+    KW_ROLE_TYPE,
+    KW_SENSITIVITY,
+    KW_SENSITIVITY_ALIAS,
+    KW_SENSITIVITY_CATEGORY,
+    KW_SENSITIVITY_ORDER,
+    KW_SID,
+    KW_SID_CONTEXT,
+    KW_SID_ORDER,
+    KW_STRING,
+    KW_TUNABLE,
+    KW_TYPE,
+    KW_TYPE_ALIAS,
+    KW_TYPE_ATTRIBUTE,
+    KW_TYPE_BOUNDS,
+    KW_TYPE_CHANGE,
+    KW_TYPE_MEMBER,
+    KW_TYPE_TRANSITION,
+    KW_USER,
+    KW_USER_ATTRIBUTE,
+    KW_USER_LEVEL,
+    KW_USER_RANGE,
+    KW_USER_ROLE,
+    KW_VALIDATE_TRANS,
+    /// A binary operation on two expressions, e.g. `a | b`.
+    NODE_BINARY_EXPR,
+    /// A list of items within `{ ... }`.
+    NODE_BLOCK,
+    /// An expression prefixed with the kind of symbol it refers to, e.g. `(type) a`.
+    NODE_CAST_EXPR,
+    /// A `low..high` range of categories.
+    NODE_CATEGORY_RANGE_EXPR,
+    /// An object class declaration, e.g. `class file extends file_common (execute);`.
+    NODE_CLASS_DEF,
+    /// A set of permissions that can be shared between classes, e.g. `common file (read write);`.
+    NODE_COMMON_DEF,
+    /// An `if` statement, with optional `else` or `else if` branches.
+    NODE_CONDITIONAL_STMT,
+    /// A logical AND or OR of two constraint expressions.
+    NODE_CONSTRAINT_BINARY_EXPR,
+    /// A comparison between a part of the security contexts being checked and another part or a set
+    /// of names, e.g. `u1 == u2` or `t1 != (a b)`.
+    NODE_CONSTRAINT_COMPARISON_EXPR,
+    /// A negated constraint expression: `!(t1 == t2)`.
+    NODE_CONSTRAINT_NOT_EXPR,
+    /// A parenthesized constraint expression.
+    NODE_CONSTRAINT_PAREN_EXPR,
+    /// A statement restricting permissions or relabeling based on the security contexts involved,
+    /// e.g. `constrain file (read) u1 == u2;` or `validate_trans file t1 == t2;`.
+    NODE_CONSTRAINT_STMT,
+    /// A named container.
+    NODE_CONTAINER_DEF,
+    /// A `user:role:type` security context, with an optional trailing level range.
+    NODE_CONTEXT_EXPR,
+    /// A list of parent-containers in a container declaration.
+    NODE_EXTENDS_LIST,
+    /// A statement labeling the files that match a path regular expression, e.g.
+    /// `file_context "/tmp" dir system_u:object_r:tmp_t;`.
+    NODE_FILE_CONTEXT_STMT,
+    /// A statement setting how the objects of a filesystem are labeled, e.g.
+    /// `fs_use_xattr ext4 system_u:object_r:fs_t;`.
+    NODE_FS_USE_STMT,
+    /// A statement labeling the files of a filesystem that doesn't support extended attributes,
+    /// e.g. `genfscon proc "/" system_u:object_r:proc_t;`.
+    NODE_GENFSCON_STMT,
+    /// A statement labeling a port of an InfiniBand device, e.g.
+    /// `ibendport_context mlx4_0 1 system_u:object_r:endport_t;`.
+    NODE_IBENDPORT_CONTEXT_STMT,
+    /// A statement labeling a range of InfiniBand partition keys within a subnet, e.g.
+    /// `ibpkey_context fe80:: 0x8000-0xffff system_u:object_r:pkey_t;`.
+    NODE_IBPKEY_CONTEXT_STMT,
+    /// A statement importing the items of another source file, e.g. `import "types/base.csp";`.
+    NODE_IMPORT_STMT,
+    /// A `sensitivity:categories` pair.
+    NODE_LEVEL_EXPR,
+    /// A `low-high` pair of levels.
+    NODE_LEVEL_RANGE_EXPR,
+    /// A parenthesized list of expressions, e.g. `(read write)`.
+    NODE_LIST_EXPR,
+    /// A string, path pattern, number, boolean or IP address literal.
+    NODE_LITERAL_EXPR,
+    /// The argument list of a macro call.
+    NODE_MACRO_ARGUMENT_LIST,
+    /// An argument passed to a macro, either by position or by the name of its parameter: `a` or
+    /// `name = a`.
+    NODE_MACRO_ARGUMENT_LIST_ITEM,
+    /// A macro call statement.
+    NODE_MACRO_CALL,
+    /// A macro definition and its body.
+    NODE_MACRO_DEF,
+    /// The parameter list within the parentheses of a macro definition.
+    NODE_MACRO_PARAM_LIST,
+    /// An individual item in a macro definition's parameter list.
+    NODE_MACRO_PARAM_LIST_ITEM,
+    /// A statement labeling a network interface and the packets sent through it, e.g.
+    /// `netif_context eth0 system_u:object_r:netif_t system_u:object_r:packet_t;`.
+    NODE_NETIF_CONTEXT_STMT,
+    /// A statement labeling the network nodes within an address and mask, e.g.
+    /// `node_context 192.168.0.0 255.255.255.0 system_u:object_r:node_t;`.
+    NODE_NODE_CONTEXT_STMT,
+    /// A statement declaring the order of a list of symbols, e.g. `class_order (file, dir);`.
+    NODE_ORDER_STMT,
+    /// A parenthesized expression.
+    NODE_PAREN_EXPR,
+    /// Tokens skipped by the parser while recovering from an error.
+    NODE_PARSE_ERROR,
+    /// A reference to a named item, optionally qualified with its parent containers: `a.b.c`.
+    NODE_PATH_EXPR,
+    /// A declaration of a domain whose denials are logged but not enforced, e.g.
+    /// `permissive httpd_t;`.
+    NODE_PERMISSIVE_DEF,
+    /// A statement labeling a port or range of ports of a network protocol, e.g.
+    /// `port_context tcp 8000-8080 system_u:object_r:app_port_t;`.
+    NODE_PORT_CONTEXT_STMT,
+    /// A unary expression with a token preceding another expression.
+    NODE_PREFIX_EXPR,
+    /// A rule allowing a role to transition to another role, e.g. `role_allow a b;`.
+    NODE_ROLE_ALLOW_RULE,
+    /// A rule setting the default role of new processes, e.g. `role_transition r t : process r2;`.
+    NODE_ROLE_TRANSITION_RULE,
+    /// A rule associating a set of types with a role, e.g. `role_type r (a b);`.
+    NODE_ROLE_TYPE_RULE,
+    /// A statement declaring the categories that may be associated with a sensitivity, e.g.
+    /// `sensitivity_category s0 c0..c255;`.
+    NODE_SENSITIVITY_CATEGORY_STMT,
+    /// A subset of a named set, e.g. the access vector of a type enforcement rule: `file (read)`.
+    NODE_SET_EXPR,
+    /// A statement that adds or removes members of a named set, e.g. `my_attr |= my_type;`.
+    NODE_SET_MODIFIER_STMT,
+    /// A statement labeling an initial security identifier, e.g.
+    /// `sid_context kernel system_u:system_r:kernel_t;`.
+    NODE_SID_CONTEXT_STMT,
+    /// The root node of the syntax tree of a file.
+    NODE_SOURCE_FILE,
+    /// A type enforcement rule.
+    NODE_TE_RULE,
+    /// An alternative name for a type, e.g. `type_alias unconfined = unconfined_t;`.
+    NODE_TYPE_ALIAS_DEF,
+    /// A declaration that bounds the permissions of a type by those of its parent, e.g.
+    /// `type_bounds httpd_t httpd_child_t;`.
+    NODE_TYPE_BOUNDS_DEF,
+    /// A rule that sets the type used when relabeling an object.
+    NODE_TYPE_CHANGE_RULE,
+    /// A rule that sets the type of polyinstantiated members of an object.
+    NODE_TYPE_MEMBER_RULE,
+    /// A `type_transition` rule, with an optional object name that restricts the rule to objects
+    /// created with that name.
+    NODE_TYPE_TRANSITION_RULE,
+    /// A statement setting the default level of a user, e.g. `user_level u s0;`.
+    NODE_USER_LEVEL_STMT,
+    /// A statement setting the range of levels a user is cleared for, e.g. `user_range u s0-s1;`.
+    NODE_USER_RANGE_STMT,
+    /// A statement associating a set of roles with a user, e.g. `user_role u (r1 r2);`.
+    NODE_USER_ROLE_STMT,
+    /// A variable declaration.
+    NODE_VARIABLE_DEF,
+    /// A single number or a `low-high` range of numbers within an [XpermRule].
+    NODE_XPERM_RANGE,
+    /// An extended permission rule that applies to a set of operations on a class, e.g.
+    /// `allow_xperm src tgt : tcp_socket ioctl (0x8900, 0x8910-0x891f);`.
+    NODE_XPERM_RULE,
+    TOK_AMPERSAND,
+    TOK_BLOCK_COMMENT,
+    TOK_CARET,
+    TOK_CLOSE_BRACE,
+    TOK_CLOSE_PARENTHESIS,
+    TOK_COLON,
+    TOK_COMMA,
+    TOK_DOC_COMMENT,
+    TOK_DOT,
+    TOK_DOT_DOT,
+    TOK_DOUBLE_AMPERSAND,
+    TOK_DOUBLE_EQUALS,
+    TOK_DOUBLE_PIPE,
+    TOK_ELSE_KW,
+    TOK_EOF,
+    TOK_EQUALS,
+    TOK_EXCLAMATION,
+    TOK_EXCLAMATION_EQUALS,
+    TOK_FALSE,
+    TOK_HYPHEN,
+    TOK_HYPHEN_EQUALS,
+    TOK_IF_KW,
+    TOK_ILLEGAL,
+    TOK_INTEGER,
+    TOK_IPV4_ADDRESS,
+    TOK_IPV6_ADDRESS,
+    TOK_LINE_COMMENT,
+    TOK_NAME,
+    TOK_OPEN_BRACE,
+    TOK_OPEN_PARENTHESIS,
+    TOK_PATH_PATTERN,
+    TOK_PIPE,
+    TOK_PIPE_EQUALS,
+    TOK_SEMICOLON,
+    TOK_STRING,
+    TOK_TILDE,
+    TOK_TRUE,
+    TOK_UNTERMINATED_STRING,
+    TOK_WHITESPACE,
+}
+
+#[derive(Logos, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TokenKind {
+    /// A name identifier token, containing a reference to the original source data.
+    #[regex = "[a-zA-Z_][a-zA-Z0-9_]*"]
+    Name,
+    /// A string literal token, containing a reference to the original source data. Strings can't
+    /// span multiple lines.
+    #[token = "\""]
+    #[callback = "string"]
+    String,
+    /// A string literal that isn't closed before the end of its line.
+    UnterminatedString,
+    /// An unquoted file path regular expression, e.g. `/usr/bin(/.*)?`, which runs up to the next
+    /// whitespace, semicolon, comma or unbalanced parenthesis.
+    #[regex = "/(([^/* \t\r\n;\",()]|\\([^ \t\r\n;\"()]*\\))([^ \t\r\n;\",()]|\\([^ \t\r\n;\"()]*\\))*)?"]
+    PathPattern,
+    /// The `if` keyword, which begins a conditional statement.
+    #[token = "if"]
+    IfKw,
+    /// The `else` keyword, which begins the alternative branch of a conditional statement.
+    #[token = "else"]
+    ElseKw,
+    #[token = "true"]
+    True,
+    #[token = "false"]
+    False,
+    #[regex = "0[xX][0-9a-fA-F]+"]
+    #[regex = "[0-9]+"]
+    Integer,
+    /// An IPv4 address literal: `192.168.0.1`. Assembled from smaller tokens by the tokenizer.
+    Ipv4Address,
+    /// An IPv6 address literal: `fe80::1`. Assembled from smaller tokens by the tokenizer.
+    Ipv6Address,
+    /// The opening parenthesis token: `(`.
+    #[token = "("]
+    OpenParenthesis,
+    /// The closing parenthesis token: `)`.
+    #[token = ")"]
+    CloseParenthesis,
+    /// The opening brace token: `{`.
+    #[token = "{"]
+    OpenBrace,
+    /// The closing brace token: `}`.
+    #[token = "}"]
+    CloseBrace,
+    /// The semicolon token. Used to terminate statements.
+    #[token = ";"]
+    Semicolon,
+    /// The period token. Used to separate fully qualified names.
+    #[token = "."]
+    Dot,
+    /// The double period token. Used as the range operator.
+    #[token = ".."]
+    DotDot,
+    /// The colon token. Used as a security attribute delimiter.
+    #[token = ":"]
+    Colon,
+    /// The hyphen token. Used to separate the low and high ends of ranges.
+    #[token = "-"]
+    Hyphen,
+    /// The comma token. Used as a delimiter.
+    #[token = ","]
+    Comma,
+    /// The equals token. Used as an initializer and assignment token.
+    #[token = "="]
+    Equals,
+    /// The bitwise binary AND operator: `&`.
+    #[token = "&"]
+    Ampersand,
+    /// The bitwise binary OR operator: `|`.
+    #[token = "|"]
+    Pipe,
+    /// The bitwise binary XOR operator: `^`.
+    #[token = "^"]
+    Caret,
+    /// The bitwise unary NOT operator: `~`.
+    #[token = "~"]
+    Tilde,
+    /// The logical AND operator: `&&`.
+    #[token = "&&"]
+    DoubleAmpersand,
+    /// The logical OR operator: `||`.
+    #[token = "||"]
+    DoublePipe,
+    /// The logical unary NOT operator: `!`.
+    #[token = "!"]
+    Exclamation,
+    /// The equality operator, used in constraint expressions: `==`.
+    #[token = "=="]
+    DoubleEquals,
+    /// The inequality operator, used in constraint expressions: `!=`.
+    #[token = "!="]
+    ExclamationEquals,
+    /// The pipe-equals operator, used for flipping on bits in bitsets.
+    #[token = "|="]
+    PipeEquals,
+    /// The hyphen-equals operator, used for flipping off bits in bitsets.
+    #[token = "-="]
+    HyphenEquals,
+    /// A C-style line comment.
+    #[regex = "//[^\n]*"]
+    LineComment,
+    /// A C-style block comment, which may contain other block comments: `/* ... */`.
+    #[token = "/*"]
+    #[callback = "block_comment"]
+    BlockComment,
+    /// A `///` comment documenting the definition that follows it. Assembled from line comments by
+    /// the tokenizer.
+    DocComment,
+    /// Any whitespace token.
+    #[regex = "\\s"]
+    Whitespace,
+    /// An unmatched token that produced an error.
+    #[error]
+    Illegal,
+    /// A token indicating the end of file has been reached.
+    #[end]
+    Eof,
+}
+
+impl TokenKind {
+    pub fn syntax_kind(self) -> SyntaxKind {
+        use self::{SyntaxKind::*, TokenKind::*};
+
+        match self {
+            Name => TOK_NAME,
+            String => TOK_STRING,
+            UnterminatedString => TOK_UNTERMINATED_STRING,
+            PathPattern => TOK_PATH_PATTERN,
+            IfKw => TOK_IF_KW,
+            ElseKw => TOK_ELSE_KW,
+            True => TOK_TRUE,
+            False => TOK_FALSE,
+            Integer => TOK_INTEGER,
+            Ipv4Address => TOK_IPV4_ADDRESS,
+            Ipv6Address => TOK_IPV6_ADDRESS,
+            OpenParenthesis => TOK_OPEN_PARENTHESIS,
+            CloseParenthesis => TOK_CLOSE_PARENTHESIS,
+            OpenBrace => TOK_OPEN_BRACE,
+            CloseBrace => TOK_CLOSE_BRACE,
+            Semicolon => TOK_SEMICOLON,
+            Dot => TOK_DOT,
+            DotDot => TOK_DOT_DOT,
+            Colon => TOK_COLON,
+            Hyphen => TOK_HYPHEN,
+            Comma => TOK_COMMA,
+            Equals => TOK_EQUALS,
+            Ampersand => TOK_AMPERSAND,
+            Pipe => TOK_PIPE,
+            Caret => TOK_CARET,
+            Tilde => TOK_TILDE,
+            DoubleAmpersand => TOK_DOUBLE_AMPERSAND,
+            DoublePipe => TOK_DOUBLE_PIPE,
+            Exclamation => TOK_EXCLAMATION,
+            DoubleEquals => TOK_DOUBLE_EQUALS,
+            ExclamationEquals => TOK_EXCLAMATION_EQUALS,
+            PipeEquals => TOK_PIPE_EQUALS,
+            HyphenEquals => TOK_HYPHEN_EQUALS,
+            LineComment => TOK_LINE_COMMENT,
+            BlockComment => TOK_BLOCK_COMMENT,
+            DocComment => TOK_DOC_COMMENT,
+            Whitespace => TOK_WHITESPACE,
+            Illegal => TOK_ILLEGAL,
+            Eof => TOK_EOF,
+        }
+    }
+}
+
+#[repr(u16)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum KeywordKind {
+    Type,
+    TypeAttribute,
+    /// The `role` statement keyword, which declares a new role for role-based access control.
+    Role,
+    /// The `role_attribute` statement keyword, which declares a new bitset of roles.
+    RoleAttribute,
+    /// The `user` statement keyword, which declares a new security identity.
+    User,
+    /// The `user_attribute` statement keyword, which declares a new bitset of users.
+    UserAttribute,
+    /// The `optional` statement keyword, which declares a new optional container.
+    Optional,
+    /// The `sensitivity` statement keyword, which declares a new multi-level security sensitivity
+    /// level.
+    Sensitivity,
+    /// The `category` statement keyword, which declares a new multi-level security compartment for
+    /// compartmentalization.
+    Category,
+    /// The `level_range` statement keyword, which declares a new low and high pair of sensitivities
+    /// and category sets.
+    LevelRange,
+    /// The `block` statement keyword, which declares a new namespace container.
+    Block,
+    /// The `in` statement keyword, which extends an existing namespace container.
+    In,
+    /// The `abstract` modifier, used to mark a `block` as abstract.
+    Abstract,
+    /// The `extends` keyword, used to begin an inheritance list.
+    Extends,
+    /// The `allow` statement keyword, which represents an allowed type-enforcement rule.
+    Allow,
+    /// The `audit_allow` statement keyword, which represents a type-enforcement rule that logs when
+    /// allowed.
+    AuditAllow,
+    /// The `never_allow` statement keyword, which represents a build time type-enforcement check on
+    /// `allow` rules.
+    NeverAllow,
+    /// The `dont_audit` statement keyword, which represents a type-enforcement rule that prevents
+    /// logging when denied.
+    DontAudit,
+    /// The `macro` keyword, which defines a new macro.
+    Macro,
+    /// The `class` statement keyword, which declares a new object class and its permissions.
+    Class,
+    /// The `common` statement keyword, which declares a set of permissions shared between classes.
+    Common,
+    /// The `class_permission` statement keyword, which declares a named set of class permissions.
+    ClassPermission,
+    /// The `class_order` statement keyword, which declares the order of object classes in the
+    /// policy.
+    ClassOrder,
+    /// The `type_transition` statement keyword, which sets the default type of new processes and
+    /// objects.
+    TypeTransition,
+    /// The `type_change` statement keyword, which sets the type used when relabeling an object.
+    TypeChange,
+    /// The `type_member` statement keyword, which sets the type used for polyinstantiated members
+    /// of an object.
+    TypeMember,
+    /// The `role_type` statement keyword, which associates a set of types with a role.
+    RoleType,
+    /// The `role_allow` statement keyword, which allows a role to transition to another role.
+    RoleAllow,
+    /// The `role_transition` statement keyword, which sets the default role of new processes.
+    RoleTransition,
+    /// The `user_role` statement keyword, which associates a set of roles with a user.
+    UserRole,
+    /// The `user_level` statement keyword, which sets the default level of a user.
+    UserLevel,
+    /// The `user_range` statement keyword, which sets the range of levels a user is cleared for.
+    UserRange,
+    /// The `sensitivity_alias` statement keyword, which declares an alternative name for a
+    /// sensitivity.
+    SensitivityAlias,
+    /// The `category_alias` statement keyword, which declares an alternative name for a category.
+    CategoryAlias,
+    /// The `sensitivity_order` statement keyword, which declares the hierarchy of sensitivities
+    /// from lowest to highest.
+    SensitivityOrder,
+    /// The `category_order` statement keyword, which declares the order of categories.
+    CategoryOrder,
+    /// The `sensitivity_category` statement keyword, which declares the categories that may be
+    /// associated with a sensitivity.
+    SensitivityCategory,
+    /// The `constrain` statement keyword, which restricts the permissions granted by a class based
+    /// on the security contexts involved.
+    Constrain,
+    /// The `mls_constrain` statement keyword, which restricts the permissions granted by a class
+    /// based on the levels involved.
+    MlsConstrain,
+    /// The `validate_trans` statement keyword, which restricts the security contexts an object may
+    /// be relabeled between.
+    ValidateTrans,
+    /// The `mls_validate_trans` statement keyword, which restricts the levels an object may be
+    /// relabeled between.
+    MlsValidateTrans,
+    /// The `dom` constraint operator, which checks that one role or level dominates another.
+    Dom,
+    /// The `domby` constraint operator, which checks that one role or level is dominated by
+    /// another.
+    DomBy,
+    /// The `incomp` constraint operator, which checks that two roles or levels are incomparable.
+    Incomp,
+    /// The `file_context` statement keyword, which labels the files matching a path regular
+    /// expression.
+    FileContext,
+    /// The `fs_use_xattr` statement keyword, which labels a filesystem's files using their extended
+    /// attributes.
+    FsUseXattr,
+    /// The `fs_use_task` statement keyword, which labels a filesystem's objects with the context of
+    /// the creating process.
+    FsUseTask,
+    /// The `fs_use_trans` statement keyword, which labels a filesystem's objects using type
+    /// transition rules.
+    FsUseTrans,
+    /// The `genfscon` statement keyword, which labels the files of a filesystem without extended
+    /// attribute support.
+    Genfscon,
+    /// The `port_context` statement keyword, which labels a port or range of ports of a network
+    /// protocol.
+    PortContext,
+    /// The `netif_context` statement keyword, which labels a network interface and the packets sent
+    /// through it.
+    NetifContext,
+    /// The `node_context` statement keyword, which labels the network nodes within an address and
+    /// mask.
+    NodeContext,
+    /// The `ibpkey_context` statement keyword, which labels a range of InfiniBand partition keys.
+    IbPkeyContext,
+    /// The `ibendport_context` statement keyword, which labels a port of an InfiniBand device.
+    IbEndportContext,
+    /// The `bool` statement keyword, which declares a new boolean that can be toggled at runtime.
+    Bool,
+    /// The `tunable` statement keyword, which declares a new boolean that is resolved when the
+    /// policy is compiled.
+    Tunable,
+    /// The `sid` statement keyword, which declares a new initial security identifier.
+    Sid,
+    /// The `sid_order` statement keyword, which declares the order of initial security identifiers.
+    SidOrder,
+    /// The `sid_context` statement keyword, which labels an initial security identifier with a
+    /// context.
+    SidContext,
+    /// The `allow_xperm` statement keyword, which represents an allowed extended permission rule.
+    AllowXperm,
+    /// The `audit_allow_xperm` statement keyword, which represents an audited extended permission
+    /// rule.
+    AuditAllowXperm,
+    /// The `dont_audit_xperm` statement keyword, which represents a silenced extended permission
+    /// rule.
+    DontAuditXperm,
+    /// The `never_allow_xperm` statement keyword, which represents a forbidden extended permission
+    /// rule.
+    NeverAllowXperm,
+    /// The `ioctl` extended permission kind, used to select `ioctl` command numbers.
+    Ioctl,
+    /// The `type_alias` statement keyword, which declares an alternative name for a type.
+    TypeAlias,
+    /// The `type_bounds` statement keyword, which bounds the permissions of a type by those of a
+    /// parent type.
+    TypeBounds,
+    /// The `permissive` statement keyword, which marks a type as a permissive domain.
+    Permissive,
+    /// The `import` statement keyword, which includes the items of another source file.
+    Import,
+    /// The `category_set` macro parameter type, which passes a set of categories.
+    CategorySet,
+    /// The `level` macro parameter type, which passes a sensitivity and its categories.
+    Level,
+    /// The `ip_address` macro parameter type, which passes an IPv4 or IPv6 address.
+    IpAddress,
+    /// The `string` macro parameter type, which passes a quoted string.
+    String,
+    /// The `name` macro parameter type, which passes a quoted object name.
+    Name,
+}
+
+impl From<KeywordKind> for SyntaxKind {
+    fn from(kw: KeywordKind) -> Self {
+        use self::{KeywordKind::*, SyntaxKind::*};
+
+        match kw {
+            Type => KW_TYPE,
+            TypeAttribute => KW_TYPE_ATTRIBUTE,
+            Role => KW_ROLE,
+            RoleAttribute => KW_ROLE_ATTRIBUTE,
+            User => KW_USER,
+            UserAttribute => KW_USER_ATTRIBUTE,
+            Optional => KW_OPTIONAL,
+            Sensitivity => KW_SENSITIVITY,
+            Category => KW_CATEGORY,
+            LevelRange => KW_LEVEL_RANGE,
+            Block => KW_BLOCK,
+            In => KW_IN,
+            Abstract => KW_ABSTRACT,
+            Extends => KW_EXTENDS,
+            Allow => KW_ALLOW,
+            AuditAllow => KW_AUDIT_ALLOW,
+            NeverAllow => KW_NEVER_ALLOW,
+            DontAudit => KW_DONT_AUDIT,
+            Macro => KW_MACRO,
+            Class => KW_CLASS,
+            Common => KW_COMMON,
+            ClassPermission => KW_CLASS_PERMISSION,
+            ClassOrder => KW_CLASS_ORDER,
+            TypeTransition => KW_TYPE_TRANSITION,
+            TypeChange => KW_TYPE_CHANGE,
+            TypeMember => KW_TYPE_MEMBER,
+            RoleType => KW_ROLE_TYPE,
+            RoleAllow => KW_ROLE_ALLOW,
+            RoleTransition => KW_ROLE_TRANSITION,
+            UserRole => KW_USER_ROLE,
+            UserLevel => KW_USER_LEVEL,
+            UserRange => KW_USER_RANGE,
+            SensitivityAlias => KW_SENSITIVITY_ALIAS,
+            CategoryAlias => KW_CATEGORY_ALIAS,
+            SensitivityOrder => KW_SENSITIVITY_ORDER,
+            CategoryOrder => KW_CATEGORY_ORDER,
+            SensitivityCategory => KW_SENSITIVITY_CATEGORY,
+            Constrain => KW_CONSTRAIN,
+            MlsConstrain => KW_MLS_CONSTRAIN,
+            ValidateTrans => KW_VALIDATE_TRANS,
+            MlsValidateTrans => KW_MLS_VALIDATE_TRANS,
+            Dom => KW_DOM,
+            DomBy => KW_DOMBY,
+            Incomp => KW_INCOMP,
+            FileContext => KW_FILE_CONTEXT,
+            FsUseXattr => KW_FS_USE_XATTR,
+            FsUseTask => KW_FS_USE_TASK,
+            FsUseTrans => KW_FS_USE_TRANS,
+            Genfscon => KW_GENFSCON,
+            PortContext => KW_PORT_CONTEXT,
+            NetifContext => KW_NETIF_CONTEXT,
+            NodeContext => KW_NODE_CONTEXT,
+            IbPkeyContext => KW_IBPKEY_CONTEXT,
+            IbEndportContext => KW_IBENDPORT_CONTEXT,
+            Bool => KW_BOOL,
+            Tunable => KW_TUNABLE,
+            Sid => KW_SID,
+            SidOrder => KW_SID_ORDER,
+            SidContext => KW_SID_CONTEXT,
+            AllowXperm => KW_ALLOW_XPERM,
+            AuditAllowXperm => KW_AUDIT_ALLOW_XPERM,
+            DontAuditXperm => KW_DONT_AUDIT_XPERM,
+            NeverAllowXperm => KW_NEVER_ALLOW_XPERM,
+            Ioctl => KW_IOCTL,
+            TypeAlias => KW_TYPE_ALIAS,
+            TypeBounds => KW_TYPE_BOUNDS,
+            Permissive => KW_PERMISSIVE,
+            Import => KW_IMPORT,
+            CategorySet => KW_CATEGORY_SET,
+            Level => KW_LEVEL,
+            IpAddress => KW_IP_ADDRESS,
+            String => KW_STRING,
+            Name => KW_NAME,
+        }
+    }
+}
+
+impl FromStr for KeywordKind {
+    type Err = ();
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        use self::KeywordKind::*;
+
+        let kw = match text {
+            "type" => Type,
+            "type_attribute" => TypeAttribute,
+            "role" => Role,
+            "role_attribute" => RoleAttribute,
+            "user" => User,
+            "user_attribute" => UserAttribute,
+            "optional" => Optional,
+            "sensitivity" => Sensitivity,
+            "category" => Category,
+            "level_range" => LevelRange,
+            "block" => Block,
+            "in" => In,
+            "abstract" => Abstract,
+            "extends" => Extends,
+            "allow" => Allow,
+            "audit_allow" => AuditAllow,
+            "never_allow" => NeverAllow,
+            "dont_audit" => DontAudit,
+            "macro" => Macro,
+            "class" => Class,
+            "common" => Common,
+            "class_permission" => ClassPermission,
+            "class_order" => ClassOrder,
+            "type_transition" => TypeTransition,
+            "type_change" => TypeChange,
+            "type_member" => TypeMember,
+            "role_type" => RoleType,
+            "role_allow" => RoleAllow,
+            "role_transition" => RoleTransition,
+            "user_role" => UserRole,
+            "user_level" => UserLevel,
+            "user_range" => UserRange,
+            "sensitivity_alias" => SensitivityAlias,
+            "category_alias" => CategoryAlias,
+            "sensitivity_order" => SensitivityOrder,
+            "category_order" => CategoryOrder,
+            "sensitivity_category" => SensitivityCategory,
+            "constrain" => Constrain,
+            "mls_constrain" => MlsConstrain,
+            "validate_trans" => ValidateTrans,
+            "mls_validate_trans" => MlsValidateTrans,
+            "dom" => Dom,
+            "domby" => DomBy,
+            "incomp" => Incomp,
+            "file_context" => FileContext,
+            "fs_use_xattr" => FsUseXattr,
+            "fs_use_task" => FsUseTask,
+            "fs_use_trans" => FsUseTrans,
+            "genfscon" => Genfscon,
+            "port_context" => PortContext,
+            "netif_context" => NetifContext,
+            "node_context" => NodeContext,
+            "ibpkey_context" => IbPkeyContext,
+            "ibendport_context" => IbEndportContext,
+            "bool" => Bool,
+            "tunable" => Tunable,
+            "sid" => Sid,
+            "sid_order" => SidOrder,
+            "sid_context" => SidContext,
+            "allow_xperm" => AllowXperm,
+            "audit_allow_xperm" => AuditAllowXperm,
+            "dont_audit_xperm" => DontAuditXperm,
+            "never_allow_xperm" => NeverAllowXperm,
+            "ioctl" => Ioctl,
+            "type_alias" => TypeAlias,
+            "type_bounds" => TypeBounds,
+            "permissive" => Permissive,
+            "import" => Import,
+            "category_set" => CategorySet,
+            "level" => Level,
+            "ip_address" => IpAddress,
+            "string" => String,
+            "name" => Name,
+            _ => return Err(()),
+        };
+
+        Ok(kw)
+    }
+}
+
+impl AsRef<str> for KeywordKind {
+    fn as_ref(&self) -> &str {
+        use self::KeywordKind::*;
+
+        match self {
+            Type => "type",
+            TypeAttribute => "type_attribute",
+            Role => "role",
+            RoleAttribute => "role_attribute",
+            User => "user",
+            UserAttribute => "user_attribute",
+            Optional => "optional",
+            Sensitivity => "sensitivity",
+            Category => "category",
+            LevelRange => "level_range",
+            Block => "block",
+            In => "in",
+            Abstract => "abstract",
+            Extends => "extends",
+            Allow => "allow",
+            AuditAllow => "audit_allow",
+            NeverAllow => "never_allow",
+            DontAudit => "dont_audit",
+            Macro => "macro",
+            Class => "class",
+            Common => "common",
+            ClassPermission => "class_permission",
+            ClassOrder => "class_order",
+            TypeTransition => "type_transition",
+            TypeChange => "type_change",
+            TypeMember => "type_member",
+            RoleType => "role_type",
+            RoleAllow => "role_allow",
+            RoleTransition => "role_transition",
+            UserRole => "user_role",
+            UserLevel => "user_level",
+            UserRange => "user_range",
+            SensitivityAlias => "sensitivity_alias",
+            CategoryAlias => "category_alias",
+            SensitivityOrder => "sensitivity_order",
+            CategoryOrder => "category_order",
+            SensitivityCategory => "sensitivity_category",
+            Constrain => "constrain",
+            MlsConstrain => "mls_constrain",
+            ValidateTrans => "validate_trans",
+            MlsValidateTrans => "mls_validate_trans",
+            Dom => "dom",
+            DomBy => "domby",
+            Incomp => "incomp",
+            FileContext => "file_context",
+            FsUseXattr => "fs_use_xattr",
+            FsUseTask => "fs_use_task",
+            FsUseTrans => "fs_use_trans",
+            Genfscon => "genfscon",
+            PortContext => "port_context",
+            NetifContext => "netif_context",
+            NodeContext => "node_context",
+            IbPkeyContext => "ibpkey_context",
+            IbEndportContext => "ibendport_context",
+            Bool => "bool",
+            Tunable => "tunable",
+            Sid => "sid",
+            SidOrder => "sid_order",
+            SidContext => "sid_context",
+            AllowXperm => "allow_xperm",
+            AuditAllowXperm => "audit_allow_xperm",
+            DontAuditXperm => "dont_audit_xperm",
+            NeverAllowXperm => "never_allow_xperm",
+            Ioctl => "ioctl",
+            TypeAlias => "type_alias",
+            TypeBounds => "type_bounds",
+            Permissive => "permissive",
+            Import => "import",
+            CategorySet => "category_set",
+            Level => "level",
+            IpAddress => "ip_address",
+            String => "string",
+            Name => "name",
+        }
+    }
+}
+
+impl KeywordKind {
+    /// Find the keyword that a parser remapped to the given [kind], if any.
+    pub fn from_syntax_kind(kind: SyntaxKind) -> Option<Self> {
+        use self::{KeywordKind::*, SyntaxKind::*};
+
+        let kw = match kind {
+            KW_TYPE => Type,
+            KW_TYPE_ATTRIBUTE => TypeAttribute,
+            KW_ROLE => Role,
+            KW_ROLE_ATTRIBUTE => RoleAttribute,
+            KW_USER => User,
+            KW_USER_ATTRIBUTE => UserAttribute,
+            KW_OPTIONAL => Optional,
+            KW_SENSITIVITY => Sensitivity,
+            KW_CATEGORY => Category,
+            KW_LEVEL_RANGE => LevelRange,
+            KW_BLOCK => Block,
+            KW_IN => In,
+            KW_ABSTRACT => Abstract,
+            KW_EXTENDS => Extends,
+            KW_ALLOW => Allow,
+            KW_AUDIT_ALLOW => AuditAllow,
+            KW_NEVER_ALLOW => NeverAllow,
+            KW_DONT_AUDIT => DontAudit,
+            KW_MACRO => Macro,
+            KW_CLASS => Class,
+            KW_COMMON => Common,
+            KW_CLASS_PERMISSION => ClassPermission,
+            KW_CLASS_ORDER => ClassOrder,
+            KW_TYPE_TRANSITION => TypeTransition,
+            KW_TYPE_CHANGE => TypeChange,
+            KW_TYPE_MEMBER => TypeMember,
+            KW_ROLE_TYPE => RoleType,
+            KW_ROLE_ALLOW => RoleAllow,
+            KW_ROLE_TRANSITION => RoleTransition,
+            KW_USER_ROLE => UserRole,
+            KW_USER_LEVEL => UserLevel,
+            KW_USER_RANGE => UserRange,
+            KW_SENSITIVITY_ALIAS => SensitivityAlias,
+            KW_CATEGORY_ALIAS => CategoryAlias,
+            KW_SENSITIVITY_ORDER => SensitivityOrder,
+            KW_CATEGORY_ORDER => CategoryOrder,
+            KW_SENSITIVITY_CATEGORY => SensitivityCategory,
+            KW_CONSTRAIN => Constrain,
+            KW_MLS_CONSTRAIN => MlsConstrain,
+            KW_VALIDATE_TRANS => ValidateTrans,
+            KW_MLS_VALIDATE_TRANS => MlsValidateTrans,
+            KW_DOM => Dom,
+            KW_DOMBY => DomBy,
+            KW_INCOMP => Incomp,
+            KW_FILE_CONTEXT => FileContext,
+            KW_FS_USE_XATTR => FsUseXattr,
+            KW_FS_USE_TASK => FsUseTask,
+            KW_FS_USE_TRANS => FsUseTrans,
+            KW_GENFSCON => Genfscon,
+            KW_PORT_CONTEXT => PortContext,
+            KW_NETIF_CONTEXT => NetifContext,
+            KW_NODE_CONTEXT => NodeContext,
+            KW_IBPKEY_CONTEXT => IbPkeyContext,
+            KW_IBENDPORT_CONTEXT => IbEndportContext,
+            KW_BOOL => Bool,
+            KW_TUNABLE => Tunable,
+            KW_SID => Sid,
+            KW_SID_ORDER => SidOrder,
+            KW_SID_CONTEXT => SidContext,
+            KW_ALLOW_XPERM => AllowXperm,
+            KW_AUDIT_ALLOW_XPERM => AuditAllowXperm,
+            KW_DONT_AUDIT_XPERM => DontAuditXperm,
+            KW_NEVER_ALLOW_XPERM => NeverAllowXperm,
+            KW_IOCTL => Ioctl,
+            KW_TYPE_ALIAS => TypeAlias,
+            KW_TYPE_BOUNDS => TypeBounds,
+            KW_PERMISSIVE => Permissive,
+            KW_IMPORT => Import,
+            KW_CATEGORY_SET => CategorySet,
+            KW_LEVEL => Level,
+            KW_IP_ADDRESS => IpAddress,
+            KW_STRING => String,
+            KW_NAME => Name,
+            _ => return None,
+        };
+
+        Some(kw)
+    }
+
+    /// Check if variables can be declared with this keyword.
+    pub fn is_var_type(self) -> bool {
+        use self::KeywordKind::*;
+
+        matches!(
+            self,
+            Type | TypeAttribute
+                | Role
+                | RoleAttribute
+                | User
+                | UserAttribute
+                | Sensitivity
+                | Category
+                | LevelRange
+                | ClassPermission
+                | SensitivityAlias
+                | CategoryAlias
+                | Bool
+                | Tunable
+                | Sid
+        )
+    }
+
+    /// Check if this keyword is a type that macro parameters can be declared with.
+    pub fn is_param_type(self) -> bool {
+        use self::KeywordKind::*;
+
+        matches!(
+            self,
+            Type | TypeAttribute
+                | Role
+                | RoleAttribute
+                | User
+                | UserAttribute
+                | Sensitivity
+                | Category
+                | LevelRange
+                | Class
+                | ClassPermission
+                | Bool
+                | CategorySet
+                | Level
+                | IpAddress
+                | String
+                | Name
+        )
+    }
+
+    /// Check if this keyword is only a keyword in a macro parameter list, and is otherwise free to
+    /// be used as an identifier.
+    pub fn is_param_only(self) -> bool {
+        use self::KeywordKind::*;
+
+        matches!(self, CategorySet | Level | IpAddress | String | Name)
+    }
+}
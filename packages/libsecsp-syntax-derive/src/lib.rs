@@ -75,7 +75,9 @@ impl ToTokens for AstEnum {
             });
 
             kind_matcher.extend(quote! {
-                secsp_parser::syntax::SyntaxKind::#kind_name => Some(#ident::#enum_name(#ast_name(syntax))),
+                secsp_parser::syntax::SyntaxKind::#kind_name => {
+                    <#ast_name as crate::ast::AstNode>::cast(syntax).map(#ident::#enum_name)
+                }
             });
 
             tokens.extend(quote! {
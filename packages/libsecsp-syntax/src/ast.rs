@@ -2,9 +2,10 @@ use std::marker::PhantomData;
 
 use itertools::Itertools;
 pub use rowan::WalkEvent;
+pub use secsp_parser::syntax::KeywordKind;
 use text_unit::TextUnit;
 
-use secsp_parser::syntax::{SyntaxNode, SyntaxNodeChildren};
+use secsp_parser::syntax::{SyntaxNode, SyntaxNodeChildren, SyntaxToken};
 
 pub use self::types::*;
 
@@ -52,6 +53,16 @@ impl<N: AstNode> Iterator for AstChildren<N> {
     }
 }
 
+/// Find the first token that is a direct child of [node] and accepted by the [map] function.
+pub(crate) fn find_token<T, F>(node: &SyntaxNode, map: F) -> Option<T>
+where
+    F: Fn(&SyntaxToken) -> Option<T>,
+{
+    node.children_with_tokens()
+        .filter_map(|child| child.into_token())
+        .find_map(|tok| map(&tok))
+}
+
 pub fn descendants(tree: &SyntaxNode) -> impl Iterator<Item = SyntaxNode> {
     tree.preorder().filter_map(|event| match event {
         WalkEvent::Enter(node) => Some(node),
@@ -1,10 +1,10 @@
 use secsp_parser::syntax::{SyntaxElement, SyntaxKind};
 
-use crate::ast::types::{Block, Definition};
+use crate::ast::types::{Block, Item};
 use crate::ast::{AstChildren, AstNode};
 
 pub trait ItemOwner: AstNode {
-    fn items(&self) -> AstChildren<Definition> {
+    fn items(&self) -> AstChildren<Item> {
         self.child::<Block>().children()
     }
 
@@ -29,6 +29,36 @@ pub trait NameOwner: AstNode {
             })
     }
 }
+
+pub trait DocCommentsOwner: AstNode {
+    /// Get the text of the `///` comments directly before this node, without their markers.
+    fn doc_comment_text(&self) -> Option<String> {
+        let lines: Vec<String> = self
+            .syntax()
+            .children_with_tokens()
+            .filter_map(|child| child.into_token())
+            .take_while(|tok| {
+                tok.kind() == SyntaxKind::TOK_DOC_COMMENT
+                    || tok.kind() == SyntaxKind::TOK_WHITESPACE
+            })
+            .filter(|tok| tok.kind() == SyntaxKind::TOK_DOC_COMMENT)
+            .map(|tok| {
+                let text = tok.text().trim_start_matches("///");
+                text.strip_prefix(' ')
+                    .unwrap_or(text)
+                    .trim_end()
+                    .to_string()
+            })
+            .collect();
+
+        if lines.is_empty() {
+            None
+        } else {
+            Some(lines.join("\n"))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::ast::{ContainerDef, MacroDef, SourceFile, VariableDef};
@@ -50,6 +80,32 @@ mod tests {
             .unwrap()
     }
 
+    #[test]
+    fn doc_comments() {
+        let text = "// not a doc comment\n\n/// A block.\n///\n///   Indented.\nblock abc {}";
+        let block = parse_item::<ContainerDef>(text);
+
+        assert_eq!(
+            Some("A block.\n\n  Indented.".to_string()),
+            block.doc_comment_text()
+        );
+    }
+
+    #[test]
+    fn doc_comments_separated_by_blank_line() {
+        let var = parse_item::<VariableDef>("/// Unattached.\n\ntype t;");
+
+        assert_eq!(None, var.doc_comment_text());
+    }
+
+    #[test]
+    fn nested_doc_comments() {
+        let block = parse_item::<ContainerDef>("block a {\n    /// A macro.\n    macro m() {}\n}");
+        let m = block.items_of::<MacroDef>().next().unwrap();
+
+        assert_eq!(Some("A macro.".to_string()), m.doc_comment_text());
+    }
+
     #[test]
     fn variable_as_name_owner() {
         test_name_owner::<VariableDef>("type t;", "t");
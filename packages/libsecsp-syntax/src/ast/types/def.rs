@@ -1,46 +1,186 @@
-use secsp_parser::syntax::SyntaxNode;
-use secsp_syntax_derive::AstEnum;
-use secsp_syntax_derive::AstType;
-
-use crate::ast::types::{ItemOwner, NameOwner};
-
-#[repr(transparent)]
-#[derive(AstType)]
-#[ast(kind = "NODE_CONTAINER_DEF")]
-pub struct ContainerDef(SyntaxNode);
-
-impl ContainerDef {}
-impl NameOwner for ContainerDef {}
-impl ItemOwner for ContainerDef {}
-
-#[repr(transparent)]
-#[derive(AstType)]
-#[ast(kind = "NODE_MACRO_DEF")]
-pub struct MacroDef(SyntaxNode);
-
-impl NameOwner for MacroDef {}
-impl ItemOwner for MacroDef {}
-
-#[repr(transparent)]
-#[derive(AstType)]
-#[ast(kind = "NODE_VARIABLE_DEF")]
-pub struct VariableDef(SyntaxNode);
-
-impl NameOwner for VariableDef {}
-//impl VariableDef {
-//    pub fn initializer(&self) -> Option<Expr> {
-//        self.children::<Expr>().next()
-//    }
-//}
-
-#[derive(AstEnum)]
-pub enum Definition {
-    #[ast(kind = "NODE_CONTAINER_DEF")]
-    Container(ContainerDef),
-
-    #[ast(kind = "NODE_MACRO_DEF")]
-    Macro(MacroDef),
-
-    #[ast(kind = "NODE_VARIABLE_DEF")]
-    Variable(VariableDef),
+use secsp_parser::syntax::{KeywordKind, SyntaxKind};
+
+use crate::ast::types::*;
+use crate::ast::{find_token, AstNode};
+
+/// The type of namespace declared by a [ContainerDef].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ContainerKind {
+    /// A new namespace declared with the `block` keyword.
+    Block,
+    /// A namespace that is omitted from the policy if any of its contents are invalid.
+    Optional,
+    /// An extension of an existing namespace declared with the `in` keyword.
+    Extension,
+}
+
+impl ContainerDef {
+    pub fn container_kind(&self) -> Option<ContainerKind> {
+        find_token(self.syntax(), |tok| match tok.kind() {
+            SyntaxKind::KW_BLOCK => Some(ContainerKind::Block),
+            SyntaxKind::KW_OPTIONAL => Some(ContainerKind::Optional),
+            SyntaxKind::KW_IN => Some(ContainerKind::Extension),
+            _ => None,
+        })
+    }
+
+    pub fn is_abstract(&self) -> bool {
+        find_token(self.syntax(), |tok| match tok.kind() {
+            SyntaxKind::KW_ABSTRACT => Some(()),
+            _ => None,
+        })
+        .is_some()
+    }
+}
+
+impl MacroParam {
+    pub fn param_kind(&self) -> Option<KeywordKind> {
+        find_token(self.syntax(), |tok| {
+            KeywordKind::from_syntax_kind(tok.kind())
+        })
+    }
+}
+
+impl VariableDef {
+    pub fn var_kind(&self) -> Option<KeywordKind> {
+        find_token(self.syntax(), |tok| {
+            KeywordKind::from_syntax_kind(tok.kind())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::testing::parse_and_find;
+    use crate::ast::types::LiteralKind;
+
+    use super::*;
+
+    #[test]
+    fn abstract_block_with_parents() {
+        let container: ContainerDef = parse_and_find("abstract block a extends b, .c.d {}");
+        let parents: Vec<String> = container
+            .extends_list()
+            .expect("no extends list")
+            .paths()
+            .map(|path| path.path())
+            .collect();
+
+        assert_eq!(Some(ContainerKind::Block), container.container_kind());
+        assert!(container.is_abstract());
+        assert_eq!(vec!["b", ".c.d"], parents);
+    }
+
+    #[test]
+    fn extension_container() {
+        let container: ContainerDef = parse_and_find("in a {}");
+
+        assert_eq!(Some(ContainerKind::Extension), container.container_kind());
+        assert!(!container.is_abstract());
+        assert!(container.extends_list().is_none());
+    }
+
+    #[test]
+    fn macro_params() {
+        let macro_def: MacroDef = parse_and_find("macro m(type a, role b) {}");
+        let params: Vec<_> = macro_def
+            .param_list()
+            .expect("no parameter list")
+            .params()
+            .map(|param| (param.param_kind(), param.name()))
+            .collect();
+
+        assert_eq!(
+            vec![
+                (Some(KeywordKind::Type), Some("a".to_string())),
+                (Some(KeywordKind::Role), Some("b".to_string()))
+            ],
+            params
+        );
+    }
+
+    #[test]
+    fn macro_param_default_value() {
+        let macro_def: MacroDef = parse_and_find("macro m(type a, bool b = true) {}");
+        let defaults: Vec<_> = macro_def
+            .param_list()
+            .expect("no parameter list")
+            .params()
+            .map(|param| param.default_value().is_some())
+            .collect();
+
+        assert_eq!(vec![false, true], defaults);
+    }
+
+    #[test]
+    fn variable_with_initializer() {
+        let var: VariableDef = parse_and_find("type_attribute a = b | c;");
+
+        assert_eq!(Some(KeywordKind::TypeAttribute), var.var_kind());
+        assert!(var.initializer().is_some());
+    }
+
+    #[test]
+    fn bool_with_initial_value() {
+        let var: VariableDef = parse_and_find("bool allow_write = true;");
+
+        assert_eq!(Some(KeywordKind::Bool), var.var_kind());
+
+        match var.initializer() {
+            Some(Expr::Literal(literal)) => {
+                assert_eq!(Some(LiteralKind::Boolean), literal.kind());
+                assert_eq!("true", literal.text());
+            }
+            e => panic!("unexpected initializer: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn type_alias_actual() {
+        let alias: TypeAliasDef = parse_and_find("type_alias a = b.c;");
+
+        assert_eq!(Some("a".to_string()), alias.name());
+        assert_eq!("b.c", alias.actual().expect("no actual type").path());
+    }
+
+    #[test]
+    fn type_bounds_operands() {
+        let bounds: TypeBoundsDef = parse_and_find("type_bounds parent_t child_t;");
+
+        assert_eq!("parent_t", bounds.parent().expect("no parent").path());
+        assert_eq!("child_t", bounds.child().expect("no child").path());
+    }
+
+    #[test]
+    fn class_with_common() {
+        let class: ClassDef = parse_and_find("class file extends file_common (execute);");
+        let common: Vec<String> = class
+            .extends_list()
+            .expect("no extends list")
+            .paths()
+            .map(|path| path.path())
+            .collect();
+
+        assert_eq!(Some("file".to_string()), class.name());
+        assert_eq!(vec!["file_common"], common);
+        assert_eq!(
+            1,
+            class.permissions().expect("no permissions").items().count()
+        );
+    }
+
+    #[test]
+    fn common_permissions() {
+        let common: CommonDef = parse_and_find("common file (read, write);");
+
+        assert_eq!(Some("file".to_string()), common.name());
+        assert_eq!(
+            2,
+            common
+                .permissions()
+                .expect("no permissions")
+                .items()
+                .count()
+        );
+    }
 }
@@ -1,33 +1,264 @@
-//
-//#[derive(AstEnum)]
-//pub enum Expr {
-//    #[ast(kind = "NODE_BINARY_EXPR")]
-//    Binary(BinaryExpr),
-//
-//    #[ast(kind = "NODE_CATEGORY_RANGE_EXPR")]
-//    CategoryRange(CategoryRangeExpr),
-//
-//    #[ast(kind = "NODE_LEVEL_EXPR")]
-//    Level(LevelExpr),
-//
-//    #[ast(kind = "NODE_LEVEL_RANGE_EXPR")]
-//    LevelRange(LevelRangeExpr),
-//
-//    #[ast(kind = "NODE_CONTAINER_EXPR")]
-//    Context(ContextExpr),
-//
-//    #[ast(kind = "NODE_LITERAL_EXPR")]
-//    Literal(LiteralExpr),
-//
-//    #[ast(kind = "NODE_LIST_EXPR")]
-//    List(ListExpr),
-//
-//    #[ast(kind = "NODE_PATH_EXPR")]
-//    Path(PathExpr),
-//
-//    #[ast(kind = "NODE_PAREN_EXPR")]
-//    Paren(ParenExpr),
-//
-//    #[ast(kind = "NODE_PREFIX_EXPR")]
-//    Prefix(PrefixExpr),
-//}
+use secsp_parser::syntax::{KeywordKind, SyntaxKind};
+
+use crate::ast::types::*;
+use crate::ast::{find_token, AstNode};
+use crate::escape;
+
+/// The operator of a [BinaryExpr].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BinaryOp {
+    LogicalAnd,
+    LogicalOr,
+    BitwiseAnd,
+    BitwiseOr,
+    BitwiseXor,
+}
+
+/// The operator of a [PrefixExpr].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PrefixOp {
+    LogicalNot,
+    BitwiseNot,
+}
+
+impl BinaryExpr {
+    pub fn op(&self) -> Option<BinaryOp> {
+        find_token(self.syntax(), |tok| match tok.kind() {
+            SyntaxKind::TOK_DOUBLE_AMPERSAND => Some(BinaryOp::LogicalAnd),
+            SyntaxKind::TOK_DOUBLE_PIPE => Some(BinaryOp::LogicalOr),
+            SyntaxKind::TOK_AMPERSAND => Some(BinaryOp::BitwiseAnd),
+            SyntaxKind::TOK_PIPE => Some(BinaryOp::BitwiseOr),
+            SyntaxKind::TOK_CARET => Some(BinaryOp::BitwiseXor),
+            _ => None,
+        })
+    }
+}
+
+impl CastExpr {
+    pub fn target_kind(&self) -> Option<KeywordKind> {
+        find_token(self.syntax(), |tok| {
+            KeywordKind::from_syntax_kind(tok.kind())
+        })
+    }
+}
+
+/// The type of value in a [LiteralExpr].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LiteralKind {
+    String,
+    /// An unquoted file path regular expression: `/usr/bin(/.*)?`.
+    PathPattern,
+    Integer,
+    Boolean,
+    Ipv4Address,
+    Ipv6Address,
+}
+
+impl LiteralExpr {
+    pub fn kind(&self) -> Option<LiteralKind> {
+        find_token(self.syntax(), |tok| match tok.kind() {
+            SyntaxKind::TOK_STRING | SyntaxKind::TOK_UNTERMINATED_STRING => {
+                Some(LiteralKind::String)
+            }
+            SyntaxKind::TOK_PATH_PATTERN => Some(LiteralKind::PathPattern),
+            SyntaxKind::TOK_INTEGER => Some(LiteralKind::Integer),
+            SyntaxKind::TOK_TRUE | SyntaxKind::TOK_FALSE => Some(LiteralKind::Boolean),
+            SyntaxKind::TOK_IPV4_ADDRESS => Some(LiteralKind::Ipv4Address),
+            SyntaxKind::TOK_IPV6_ADDRESS => Some(LiteralKind::Ipv6Address),
+            _ => None,
+        })
+    }
+
+    /// Get the source text of this literal, including the quotes of string literals.
+    pub fn text(&self) -> String {
+        find_token(self.syntax(), |tok| match tok.kind() {
+            SyntaxKind::TOK_STRING
+            | SyntaxKind::TOK_UNTERMINATED_STRING
+            | SyntaxKind::TOK_PATH_PATTERN
+            | SyntaxKind::TOK_INTEGER
+            | SyntaxKind::TOK_TRUE
+            | SyntaxKind::TOK_FALSE
+            | SyntaxKind::TOK_IPV4_ADDRESS
+            | SyntaxKind::TOK_IPV6_ADDRESS => Some(tok.text().to_string()),
+            _ => None,
+        })
+        .unwrap_or_default()
+    }
+
+    /// Get the value of a string literal, with its escape sequences replaced by the characters
+    /// they stand for. Returns `None` for other literals, and for strings that are unterminated
+    /// or contain invalid escape sequences.
+    pub fn unescape(&self) -> Option<String> {
+        let text = find_token(self.syntax(), |tok| match tok.kind() {
+            SyntaxKind::TOK_STRING => Some(tok.text().to_string()),
+            _ => None,
+        })?;
+
+        let mut valid = true;
+        let value = escape::unescape(&text[1..text.len() - 1], |_| valid = false);
+
+        if valid {
+            Some(value)
+        } else {
+            None
+        }
+    }
+}
+
+impl PathExpr {
+    /// Check if this path is rooted at the global namespace, e.g. `.global.item`.
+    pub fn is_global(&self) -> bool {
+        find_token(self.syntax(), |tok| match tok.kind() {
+            SyntaxKind::TOK_DOT => Some(true),
+            SyntaxKind::TOK_NAME => Some(false),
+            _ => None,
+        })
+        .unwrap_or(false)
+    }
+
+    /// Get the names that make up each segment of this path.
+    pub fn segments(&self) -> Vec<String> {
+        self.syntax()
+            .children_with_tokens()
+            .filter_map(|child| child.into_token())
+            .filter(|tok| tok.kind() == SyntaxKind::TOK_NAME)
+            .map(|tok| tok.text().to_string())
+            .collect()
+    }
+
+    /// Get the textual representation of this path, without any trivia.
+    pub fn path(&self) -> String {
+        let path = self.segments().join(".");
+
+        if self.is_global() {
+            format!(".{}", path)
+        } else {
+            path
+        }
+    }
+}
+
+impl PrefixExpr {
+    pub fn op(&self) -> Option<PrefixOp> {
+        find_token(self.syntax(), |tok| match tok.kind() {
+            SyntaxKind::TOK_EXCLAMATION => Some(PrefixOp::LogicalNot),
+            SyntaxKind::TOK_TILDE => Some(PrefixOp::BitwiseNot),
+            _ => None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::testing::parse_and_find;
+    use crate::ast::types::MacroCall;
+
+    use super::*;
+
+    fn parse_arg(arg: &str) -> Expr {
+        let call: MacroCall = parse_and_find(&format!("callstub({});", arg));
+        call.arguments()
+            .next()
+            .and_then(|argument| argument.value())
+            .expect("no argument found")
+    }
+
+    #[test]
+    fn global_path() {
+        match parse_arg(".global.item") {
+            Expr::Path(path) => {
+                assert!(path.is_global());
+                assert_eq!(vec!["global", "item"], path.segments());
+                assert_eq!(".global.item", path.path());
+            }
+            e => panic!("unexpected expression: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn unescaped_string_literal() {
+        match parse_arg(r#""a \"quoted\" \\ path""#) {
+            Expr::Literal(literal) => {
+                assert_eq!(Some(LiteralKind::String), literal.kind());
+                assert_eq!(Some(r#"a "quoted" \ path"#.to_string()), literal.unescape());
+            }
+            e => panic!("unexpected expression: {:?}", e),
+        }
+
+        match parse_arg(r#""invalid \. escape""#) {
+            Expr::Literal(literal) => assert_eq!(None, literal.unescape()),
+            e => panic!("unexpected expression: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn binary_expr_operands() {
+        match parse_arg("a && b") {
+            Expr::Binary(binary) => {
+                assert_eq!(Some(BinaryOp::LogicalAnd), binary.op());
+                assert!(binary.lhs().is_some());
+                assert!(binary.rhs().is_some());
+            }
+            e => panic!("unexpected expression: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn prefix_expr_operand() {
+        match parse_arg("~a") {
+            Expr::Prefix(prefix) => {
+                assert_eq!(Some(PrefixOp::BitwiseNot), prefix.op());
+                assert!(prefix.operand().is_some());
+            }
+            e => panic!("unexpected expression: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn cast_expr_operand() {
+        match parse_arg("(role_attribute) (a b)") {
+            Expr::Cast(cast) => {
+                assert_eq!(Some(KeywordKind::RoleAttribute), cast.target_kind());
+
+                match cast.operand() {
+                    Some(Expr::List(_)) => {}
+                    e => panic!("unexpected operand: {:?}", e),
+                }
+            }
+            e => panic!("unexpected expression: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn parenthesized_keyword_is_not_a_cast() {
+        match parse_arg("(type)") {
+            Expr::Paren(_) => {}
+            e => panic!("unexpected expression: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn string_literal_text() {
+        match parse_arg("\"test\"") {
+            Expr::Literal(literal) => assert_eq!("\"test\"", literal.text()),
+            e => panic!("unexpected expression: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn context_expr_components() {
+        match parse_arg("u:r:t:s0-s1") {
+            Expr::Context(context) => {
+                assert!(context.user().is_some());
+                assert!(context.role().is_some());
+                assert!(context.type_().is_some());
+
+                match context.level_range() {
+                    Some(Expr::LevelRange(_)) => {}
+                    e => panic!("unexpected level range: {:?}", e),
+                }
+            }
+            e => panic!("unexpected expression: {:?}", e),
+        }
+    }
+}
@@ -0,0 +1,1107 @@
+//! Generated by `secsp-codegen` from `libsecsp-parser/grammar.toml`, don't edit it by hand.
+//! Run `cargo run -p secsp-codegen` to regenerate it instead.
+
+use secsp_parser::syntax::SyntaxNode;
+use secsp_syntax_derive::{AstEnum, AstType};
+
+use crate::ast::types::*;
+use crate::ast::{AstChildren, AstNode};
+
+/// A list of items within `{ ... }`.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_BLOCK")]
+pub struct Block(SyntaxNode);
+
+impl Block {
+    pub fn items(&self) -> AstChildren<Item> {
+        self.children()
+    }
+}
+
+/// The root node of the syntax tree of a file.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_SOURCE_FILE")]
+pub struct SourceFile(SyntaxNode);
+
+impl ItemOwner for SourceFile {}
+
+/// A named container.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_CONTAINER_DEF")]
+pub struct ContainerDef(SyntaxNode);
+
+impl ContainerDef {
+    pub fn extends_list(&self) -> Option<ExtendsList> {
+        self.children().next()
+    }
+}
+
+impl NameOwner for ContainerDef {}
+impl DocCommentsOwner for ContainerDef {}
+impl ItemOwner for ContainerDef {}
+
+/// A list of parent-containers in a container declaration.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_EXTENDS_LIST")]
+pub struct ExtendsList(SyntaxNode);
+
+impl ExtendsList {
+    pub fn paths(&self) -> AstChildren<PathExpr> {
+        self.children()
+    }
+}
+
+/// A macro definition and its body.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_MACRO_DEF")]
+pub struct MacroDef(SyntaxNode);
+
+impl MacroDef {
+    pub fn param_list(&self) -> Option<MacroParamList> {
+        self.children().next()
+    }
+}
+
+impl NameOwner for MacroDef {}
+impl DocCommentsOwner for MacroDef {}
+impl ItemOwner for MacroDef {}
+
+/// The parameter list within the parentheses of a macro definition.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_MACRO_PARAM_LIST")]
+pub struct MacroParamList(SyntaxNode);
+
+impl MacroParamList {
+    pub fn params(&self) -> AstChildren<MacroParam> {
+        self.children()
+    }
+}
+
+/// An individual item in a macro definition's parameter list.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_MACRO_PARAM_LIST_ITEM")]
+pub struct MacroParam(SyntaxNode);
+
+impl MacroParam {
+    /// Get the value passed to this parameter by calls that don't supply an argument for it.
+    pub fn default_value(&self) -> Option<Expr> {
+        self.children().next()
+    }
+}
+
+impl NameOwner for MacroParam {}
+
+/// A variable declaration.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_VARIABLE_DEF")]
+pub struct VariableDef(SyntaxNode);
+
+impl VariableDef {
+    pub fn initializer(&self) -> Option<Expr> {
+        self.children().next()
+    }
+}
+
+impl NameOwner for VariableDef {}
+impl DocCommentsOwner for VariableDef {}
+
+/// An alternative name for a type, e.g. `type_alias unconfined = unconfined_t;`.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_TYPE_ALIAS_DEF")]
+pub struct TypeAliasDef(SyntaxNode);
+
+impl TypeAliasDef {
+    /// Get the type that this alias refers to.
+    pub fn actual(&self) -> Option<PathExpr> {
+        self.children().next()
+    }
+}
+
+impl NameOwner for TypeAliasDef {}
+
+/// A declaration that bounds the permissions of a type by those of its parent, e.g.
+/// `type_bounds httpd_t httpd_child_t;`.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_TYPE_BOUNDS_DEF")]
+pub struct TypeBoundsDef(SyntaxNode);
+
+impl TypeBoundsDef {
+    pub fn parent(&self) -> Option<PathExpr> {
+        self.children().next()
+    }
+
+    pub fn child(&self) -> Option<PathExpr> {
+        self.children().nth(1)
+    }
+}
+
+/// A declaration of a domain whose denials are logged but not enforced, e.g. `permissive httpd_t;`.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_PERMISSIVE_DEF")]
+pub struct PermissiveDef(SyntaxNode);
+
+impl PermissiveDef {
+    pub fn domain(&self) -> Option<PathExpr> {
+        self.children().next()
+    }
+}
+
+/// An object class declaration, e.g. `class file extends file_common (execute);`.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_CLASS_DEF")]
+pub struct ClassDef(SyntaxNode);
+
+impl ClassDef {
+    /// Get the list of commons this class inherits permissions from.
+    pub fn extends_list(&self) -> Option<ExtendsList> {
+        self.children().next()
+    }
+
+    pub fn permissions(&self) -> Option<ListExpr> {
+        self.children().next()
+    }
+}
+
+impl NameOwner for ClassDef {}
+
+/// A set of permissions that can be shared between classes, e.g. `common file (read write);`.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_COMMON_DEF")]
+pub struct CommonDef(SyntaxNode);
+
+impl CommonDef {
+    pub fn permissions(&self) -> Option<ListExpr> {
+        self.children().next()
+    }
+}
+
+impl NameOwner for CommonDef {}
+
+/// A binary operation on two expressions, e.g. `a | b`.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_BINARY_EXPR")]
+pub struct BinaryExpr(SyntaxNode);
+
+impl BinaryExpr {
+    pub fn lhs(&self) -> Option<Expr> {
+        self.children().next()
+    }
+
+    pub fn rhs(&self) -> Option<Expr> {
+        self.children().nth(1)
+    }
+}
+
+/// An expression prefixed with the kind of symbol it refers to, e.g. `(type) a`.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_CAST_EXPR")]
+pub struct CastExpr(SyntaxNode);
+
+impl CastExpr {
+    pub fn operand(&self) -> Option<Expr> {
+        self.children().next()
+    }
+}
+
+/// A `low..high` range of categories.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_CATEGORY_RANGE_EXPR")]
+pub struct CategoryRangeExpr(SyntaxNode);
+
+impl CategoryRangeExpr {
+    pub fn low(&self) -> Option<Expr> {
+        self.children().next()
+    }
+
+    pub fn high(&self) -> Option<Expr> {
+        self.children().nth(1)
+    }
+}
+
+/// A `sensitivity:categories` pair.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_LEVEL_EXPR")]
+pub struct LevelExpr(SyntaxNode);
+
+impl LevelExpr {
+    pub fn sensitivity(&self) -> Option<Expr> {
+        self.children().next()
+    }
+
+    pub fn categories(&self) -> Option<Expr> {
+        self.children().nth(1)
+    }
+}
+
+/// A `low-high` pair of levels.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_LEVEL_RANGE_EXPR")]
+pub struct LevelRangeExpr(SyntaxNode);
+
+impl LevelRangeExpr {
+    pub fn low(&self) -> Option<Expr> {
+        self.children().next()
+    }
+
+    pub fn high(&self) -> Option<Expr> {
+        self.children().nth(1)
+    }
+}
+
+/// A `user:role:type` security context, with an optional trailing level range.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_CONTEXT_EXPR")]
+pub struct ContextExpr(SyntaxNode);
+
+impl ContextExpr {
+    pub fn user(&self) -> Option<Expr> {
+        self.children().next()
+    }
+
+    pub fn role(&self) -> Option<Expr> {
+        self.children().nth(1)
+    }
+
+    pub fn type_(&self) -> Option<Expr> {
+        self.children().nth(2)
+    }
+
+    pub fn level_range(&self) -> Option<Expr> {
+        self.children().nth(3)
+    }
+}
+
+/// A string, path pattern, number, boolean or IP address literal.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_LITERAL_EXPR")]
+pub struct LiteralExpr(SyntaxNode);
+
+/// A parenthesized list of expressions, e.g. `(read write)`.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_LIST_EXPR")]
+pub struct ListExpr(SyntaxNode);
+
+impl ListExpr {
+    pub fn items(&self) -> AstChildren<Expr> {
+        self.children()
+    }
+}
+
+/// A reference to a named item, optionally qualified with its parent containers: `a.b.c`.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_PATH_EXPR")]
+pub struct PathExpr(SyntaxNode);
+
+/// A parenthesized expression.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_PAREN_EXPR")]
+pub struct ParenExpr(SyntaxNode);
+
+impl ParenExpr {
+    pub fn expr(&self) -> Option<Expr> {
+        self.children().next()
+    }
+}
+
+/// A unary expression with a token preceding another expression.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_PREFIX_EXPR")]
+pub struct PrefixExpr(SyntaxNode);
+
+impl PrefixExpr {
+    pub fn operand(&self) -> Option<Expr> {
+        self.children().next()
+    }
+}
+
+/// A subset of a named set, e.g. the access vector of a type enforcement rule: `file (read)`.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_SET_EXPR")]
+pub struct SetExpr(SyntaxNode);
+
+impl SetExpr {
+    pub fn name(&self) -> Option<Expr> {
+        self.children().next()
+    }
+
+    pub fn subset(&self) -> Option<Expr> {
+        self.children().nth(1)
+    }
+}
+
+/// A macro call statement.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_MACRO_CALL")]
+pub struct MacroCall(SyntaxNode);
+
+impl MacroCall {
+    pub fn path(&self) -> Option<PathExpr> {
+        self.children().next()
+    }
+
+    pub fn argument_list(&self) -> Option<MacroArgumentList> {
+        self.children().next()
+    }
+}
+
+/// The argument list of a macro call.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_MACRO_ARGUMENT_LIST")]
+pub struct MacroArgumentList(SyntaxNode);
+
+/// An argument passed to a macro, either by position or by the name of its parameter: `a` or
+/// `name = a`.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_MACRO_ARGUMENT_LIST_ITEM")]
+pub struct MacroArgument(SyntaxNode);
+
+impl MacroArgument {
+    pub fn value(&self) -> Option<Expr> {
+        self.children().next()
+    }
+}
+
+impl NameOwner for MacroArgument {}
+
+/// An `if` statement, with optional `else` or `else if` branches.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_CONDITIONAL_STMT")]
+pub struct ConditionalStmt(SyntaxNode);
+
+impl ConditionalStmt {
+    pub fn condition(&self) -> Option<Expr> {
+        self.children().next()
+    }
+
+    pub fn then_block(&self) -> Option<Block> {
+        self.children().next()
+    }
+
+    pub fn else_block(&self) -> Option<Block> {
+        self.children().nth(1)
+    }
+
+    pub fn else_if(&self) -> Option<ConditionalStmt> {
+        self.children().next()
+    }
+}
+
+/// A statement that adds or removes members of a named set, e.g. `my_attr |= my_type;`.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_SET_MODIFIER_STMT")]
+pub struct SetModifierStmt(SyntaxNode);
+
+impl SetModifierStmt {
+    pub fn target(&self) -> Option<PathExpr> {
+        self.children().next()
+    }
+
+    pub fn value(&self) -> Option<Expr> {
+        self.children().nth(1)
+    }
+}
+
+/// A statement declaring the order of a list of symbols, e.g. `class_order (file, dir);`.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_ORDER_STMT")]
+pub struct OrderStmt(SyntaxNode);
+
+impl OrderStmt {
+    pub fn items(&self) -> Option<ListExpr> {
+        self.children().next()
+    }
+}
+
+/// A statement importing the items of another source file, e.g. `import "types/base.csp";`.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_IMPORT_STMT")]
+pub struct ImportStmt(SyntaxNode);
+
+impl ImportStmt {
+    pub fn path(&self) -> Option<LiteralExpr> {
+        self.children().next()
+    }
+}
+
+/// A statement restricting permissions or relabeling based on the security contexts involved, e.g.
+/// `constrain file (read) u1 == u2;` or `validate_trans file t1 == t2;`.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_CONSTRAINT_STMT")]
+pub struct ConstraintStmt(SyntaxNode);
+
+impl ConstraintStmt {
+    /// Get the class and permissions of a constraint, or the class of a validatetrans statement.
+    pub fn access_vector(&self) -> Option<Expr> {
+        self.children().next()
+    }
+
+    pub fn expr(&self) -> Option<ConstraintExpr> {
+        self.children().next()
+    }
+}
+
+/// A logical AND or OR of two constraint expressions.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_CONSTRAINT_BINARY_EXPR")]
+pub struct ConstraintBinaryExpr(SyntaxNode);
+
+impl ConstraintBinaryExpr {
+    pub fn lhs(&self) -> Option<ConstraintExpr> {
+        self.children().next()
+    }
+
+    pub fn rhs(&self) -> Option<ConstraintExpr> {
+        self.children().nth(1)
+    }
+}
+
+/// A comparison between a part of the security contexts being checked and another part or a set of
+/// names, e.g. `u1 == u2` or `t1 != (a b)`.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_CONSTRAINT_COMPARISON_EXPR")]
+pub struct ConstraintComparisonExpr(SyntaxNode);
+
+impl ConstraintComparisonExpr {
+    /// Get the name of the context component being compared, e.g. `u1` or `h2`.
+    pub fn lhs(&self) -> Option<PathExpr> {
+        self.children().next()
+    }
+
+    pub fn rhs(&self) -> Option<Expr> {
+        self.children().nth(1)
+    }
+}
+
+/// A negated constraint expression: `!(t1 == t2)`.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_CONSTRAINT_NOT_EXPR")]
+pub struct ConstraintNotExpr(SyntaxNode);
+
+impl ConstraintNotExpr {
+    pub fn operand(&self) -> Option<ConstraintExpr> {
+        self.children().next()
+    }
+}
+
+/// A parenthesized constraint expression.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_CONSTRAINT_PAREN_EXPR")]
+pub struct ConstraintParenExpr(SyntaxNode);
+
+impl ConstraintParenExpr {
+    pub fn expr(&self) -> Option<ConstraintExpr> {
+        self.children().next()
+    }
+}
+
+/// A statement labeling the files that match a path regular expression, e.g.
+/// `file_context "/tmp" dir system_u:object_r:tmp_t;`.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_FILE_CONTEXT_STMT")]
+pub struct FileContextStmt(SyntaxNode);
+
+impl FileContextStmt {
+    pub fn path(&self) -> Option<LiteralExpr> {
+        self.children().next()
+    }
+}
+
+/// A statement setting how the objects of a filesystem are labeled, e.g.
+/// `fs_use_xattr ext4 system_u:object_r:fs_t;`.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_FS_USE_STMT")]
+pub struct FsUseStmt(SyntaxNode);
+
+impl FsUseStmt {
+    pub fn fs_type(&self) -> Option<PathExpr> {
+        self.children().next()
+    }
+
+    pub fn context(&self) -> Option<Expr> {
+        self.children().nth(1)
+    }
+}
+
+/// A statement labeling the files of a filesystem that doesn't support extended attributes, e.g.
+/// `genfscon proc "/" system_u:object_r:proc_t;`.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_GENFSCON_STMT")]
+pub struct GenfsconStmt(SyntaxNode);
+
+impl GenfsconStmt {
+    pub fn fs_type(&self) -> Option<PathExpr> {
+        self.children().next()
+    }
+
+    pub fn path(&self) -> Option<LiteralExpr> {
+        self.children().next()
+    }
+
+    pub fn context(&self) -> Option<Expr> {
+        self.children().nth(2)
+    }
+}
+
+/// A statement labeling a port or range of ports of a network protocol, e.g.
+/// `port_context tcp 8000-8080 system_u:object_r:app_port_t;`.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_PORT_CONTEXT_STMT")]
+pub struct PortContextStmt(SyntaxNode);
+
+impl PortContextStmt {
+    pub fn protocol(&self) -> Option<PathExpr> {
+        self.children().next()
+    }
+
+    pub fn low_port(&self) -> Option<LiteralExpr> {
+        self.children().next()
+    }
+
+    /// Get the last port of the range being labeled, if more than one port is labeled.
+    pub fn high_port(&self) -> Option<LiteralExpr> {
+        self.children().nth(1)
+    }
+}
+
+/// A statement labeling a network interface and the packets sent through it, e.g.
+/// `netif_context eth0 system_u:object_r:netif_t system_u:object_r:packet_t;`.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_NETIF_CONTEXT_STMT")]
+pub struct NetifContextStmt(SyntaxNode);
+
+impl NetifContextStmt {
+    pub fn interface(&self) -> Option<PathExpr> {
+        self.children().next()
+    }
+
+    pub fn interface_context(&self) -> Option<Expr> {
+        self.children().nth(1)
+    }
+
+    pub fn packet_context(&self) -> Option<Expr> {
+        self.children().nth(2)
+    }
+}
+
+/// A statement labeling the network nodes within an address and mask, e.g.
+/// `node_context 192.168.0.0 255.255.255.0 system_u:object_r:node_t;`.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_NODE_CONTEXT_STMT")]
+pub struct NodeContextStmt(SyntaxNode);
+
+impl NodeContextStmt {
+    pub fn address(&self) -> Option<LiteralExpr> {
+        self.children().next()
+    }
+
+    pub fn mask(&self) -> Option<LiteralExpr> {
+        self.children().nth(1)
+    }
+}
+
+/// A statement labeling a range of InfiniBand partition keys within a subnet, e.g.
+/// `ibpkey_context fe80:: 0x8000-0xffff system_u:object_r:pkey_t;`.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_IBPKEY_CONTEXT_STMT")]
+pub struct IbPkeyContextStmt(SyntaxNode);
+
+impl IbPkeyContextStmt {
+    pub fn subnet_prefix(&self) -> Option<LiteralExpr> {
+        self.children().next()
+    }
+
+    pub fn low_pkey(&self) -> Option<LiteralExpr> {
+        self.children().nth(1)
+    }
+
+    /// Get the last partition key of the range being labeled, if more than one key is labeled.
+    pub fn high_pkey(&self) -> Option<LiteralExpr> {
+        self.children().nth(2)
+    }
+}
+
+/// A statement labeling a port of an InfiniBand device, e.g.
+/// `ibendport_context mlx4_0 1 system_u:object_r:endport_t;`.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_IBENDPORT_CONTEXT_STMT")]
+pub struct IbEndportContextStmt(SyntaxNode);
+
+impl IbEndportContextStmt {
+    pub fn device(&self) -> Option<PathExpr> {
+        self.children().next()
+    }
+
+    pub fn port(&self) -> Option<LiteralExpr> {
+        self.children().next()
+    }
+
+    pub fn context(&self) -> Option<Expr> {
+        self.children().nth(2)
+    }
+}
+
+/// A statement labeling an initial security identifier, e.g.
+/// `sid_context kernel system_u:system_r:kernel_t;`.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_SID_CONTEXT_STMT")]
+pub struct SidContextStmt(SyntaxNode);
+
+impl SidContextStmt {
+    pub fn sid(&self) -> Option<PathExpr> {
+        self.children().next()
+    }
+
+    pub fn context(&self) -> Option<Expr> {
+        self.children().nth(1)
+    }
+}
+
+/// A statement declaring the categories that may be associated with a sensitivity, e.g.
+/// `sensitivity_category s0 c0..c255;`.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_SENSITIVITY_CATEGORY_STMT")]
+pub struct SensitivityCategoryStmt(SyntaxNode);
+
+impl SensitivityCategoryStmt {
+    pub fn sensitivity(&self) -> Option<PathExpr> {
+        self.children().next()
+    }
+
+    pub fn categories(&self) -> Option<Expr> {
+        self.children().nth(1)
+    }
+}
+
+/// A rule associating a set of types with a role, e.g. `role_type r (a b);`.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_ROLE_TYPE_RULE")]
+pub struct RoleTypeRule(SyntaxNode);
+
+impl RoleTypeRule {
+    pub fn role(&self) -> Option<Expr> {
+        self.children().next()
+    }
+
+    pub fn types(&self) -> Option<Expr> {
+        self.children().nth(1)
+    }
+}
+
+/// A rule allowing a role to transition to another role, e.g. `role_allow a b;`.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_ROLE_ALLOW_RULE")]
+pub struct RoleAllowRule(SyntaxNode);
+
+impl RoleAllowRule {
+    pub fn source(&self) -> Option<Expr> {
+        self.children().next()
+    }
+
+    pub fn target(&self) -> Option<Expr> {
+        self.children().nth(1)
+    }
+}
+
+/// A rule setting the default role of new processes, e.g. `role_transition r t : process r2;`.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_ROLE_TRANSITION_RULE")]
+pub struct RoleTransitionRule(SyntaxNode);
+
+impl RoleTransitionRule {
+    /// Get the current role of the process.
+    pub fn source(&self) -> Option<Expr> {
+        self.children().next()
+    }
+
+    /// Get the type of the object being executed or created.
+    pub fn target(&self) -> Option<Expr> {
+        self.children().nth(1)
+    }
+
+    pub fn class(&self) -> Option<Expr> {
+        self.children().nth(2)
+    }
+
+    pub fn result_role(&self) -> Option<Expr> {
+        self.children().nth(3)
+    }
+}
+
+/// A statement associating a set of roles with a user, e.g. `user_role u (r1 r2);`.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_USER_ROLE_STMT")]
+pub struct UserRoleStmt(SyntaxNode);
+
+impl UserRoleStmt {
+    pub fn user(&self) -> Option<Expr> {
+        self.children().next()
+    }
+
+    pub fn roles(&self) -> Option<Expr> {
+        self.children().nth(1)
+    }
+}
+
+/// A statement setting the default level of a user, e.g. `user_level u s0;`.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_USER_LEVEL_STMT")]
+pub struct UserLevelStmt(SyntaxNode);
+
+impl UserLevelStmt {
+    pub fn user(&self) -> Option<Expr> {
+        self.children().next()
+    }
+
+    pub fn level(&self) -> Option<Expr> {
+        self.children().nth(1)
+    }
+}
+
+/// A statement setting the range of levels a user is cleared for, e.g. `user_range u s0-s1;`.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_USER_RANGE_STMT")]
+pub struct UserRangeStmt(SyntaxNode);
+
+impl UserRangeStmt {
+    pub fn user(&self) -> Option<Expr> {
+        self.children().next()
+    }
+
+    pub fn range(&self) -> Option<Expr> {
+        self.children().nth(1)
+    }
+}
+
+/// A type enforcement rule.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_TE_RULE")]
+pub struct TeRule(SyntaxNode);
+
+impl TeRule {
+    pub fn source(&self) -> Option<Expr> {
+        self.children().next()
+    }
+
+    pub fn target(&self) -> Option<Expr> {
+        self.children().nth(1)
+    }
+
+    /// Get the class and permissions expression that follows the `:` in this rule.
+    pub fn access_vector(&self) -> Option<Expr> {
+        self.children().nth(2)
+    }
+}
+
+/// An extended permission rule that applies to a set of operations on a class, e.g.
+/// `allow_xperm src tgt : tcp_socket ioctl (0x8900, 0x8910-0x891f);`.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_XPERM_RULE")]
+pub struct XpermRule(SyntaxNode);
+
+impl XpermRule {
+    pub fn source(&self) -> Option<Expr> {
+        self.children().next()
+    }
+
+    pub fn target(&self) -> Option<Expr> {
+        self.children().nth(1)
+    }
+
+    pub fn class(&self) -> Option<Expr> {
+        self.children().nth(2)
+    }
+
+    pub fn ranges(&self) -> AstChildren<XpermRange> {
+        self.children()
+    }
+}
+
+/// A single number or a `low-high` range of numbers within an [XpermRule].
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_XPERM_RANGE")]
+pub struct XpermRange(SyntaxNode);
+
+impl XpermRange {
+    pub fn low(&self) -> Option<LiteralExpr> {
+        self.children().next()
+    }
+
+    /// Get the last number of this range, if it contains more than one number.
+    pub fn high(&self) -> Option<LiteralExpr> {
+        self.children().nth(1)
+    }
+}
+
+/// A `type_transition` rule, with an optional object name that restricts the rule to objects
+/// created with that name.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_TYPE_TRANSITION_RULE")]
+pub struct TypeTransitionRule(SyntaxNode);
+
+impl TypeTransitionRule {
+    pub fn object_name(&self) -> Option<LiteralExpr> {
+        self.children().next()
+    }
+}
+
+impl TypeRule for TypeTransitionRule {}
+
+/// A rule that sets the type used when relabeling an object.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_TYPE_CHANGE_RULE")]
+pub struct TypeChangeRule(SyntaxNode);
+
+impl TypeRule for TypeChangeRule {}
+
+/// A rule that sets the type of polyinstantiated members of an object.
+#[repr(transparent)]
+#[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]
+#[ast(kind = "NODE_TYPE_MEMBER_RULE")]
+pub struct TypeMemberRule(SyntaxNode);
+
+impl TypeRule for TypeMemberRule {}
+
+/// Any item that declares a named symbol.
+#[derive(AstEnum, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Definition {
+    #[ast(kind = "NODE_CONTAINER_DEF")]
+    Container(ContainerDef),
+
+    #[ast(kind = "NODE_MACRO_DEF")]
+    Macro(MacroDef),
+
+    #[ast(kind = "NODE_VARIABLE_DEF")]
+    Variable(VariableDef),
+
+    #[ast(kind = "NODE_TYPE_ALIAS_DEF")]
+    TypeAlias(TypeAliasDef),
+
+    #[ast(kind = "NODE_CLASS_DEF")]
+    Class(ClassDef),
+
+    #[ast(kind = "NODE_COMMON_DEF")]
+    Common(CommonDef),
+}
+
+/// Any expression.
+#[derive(AstEnum, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Expr {
+    #[ast(kind = "NODE_BINARY_EXPR")]
+    Binary(BinaryExpr),
+
+    #[ast(kind = "NODE_CAST_EXPR")]
+    Cast(CastExpr),
+
+    #[ast(kind = "NODE_CATEGORY_RANGE_EXPR")]
+    CategoryRange(CategoryRangeExpr),
+
+    #[ast(kind = "NODE_LEVEL_EXPR")]
+    Level(LevelExpr),
+
+    #[ast(kind = "NODE_LEVEL_RANGE_EXPR")]
+    LevelRange(LevelRangeExpr),
+
+    #[ast(kind = "NODE_CONTEXT_EXPR")]
+    Context(ContextExpr),
+
+    #[ast(kind = "NODE_LITERAL_EXPR")]
+    Literal(LiteralExpr),
+
+    #[ast(kind = "NODE_LIST_EXPR")]
+    List(ListExpr),
+
+    #[ast(kind = "NODE_PATH_EXPR")]
+    Path(PathExpr),
+
+    #[ast(kind = "NODE_PAREN_EXPR")]
+    Paren(ParenExpr),
+
+    #[ast(kind = "NODE_PREFIX_EXPR")]
+    Prefix(PrefixExpr),
+
+    #[ast(kind = "NODE_SET_EXPR")]
+    Set(SetExpr),
+}
+
+/// Any statement or declaration that can appear within a block.
+#[derive(AstEnum, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Item {
+    #[ast(kind = "NODE_CONTAINER_DEF")]
+    Container(ContainerDef),
+
+    #[ast(kind = "NODE_MACRO_DEF")]
+    Macro(MacroDef),
+
+    #[ast(kind = "NODE_VARIABLE_DEF")]
+    Variable(VariableDef),
+
+    #[ast(kind = "NODE_TYPE_ALIAS_DEF")]
+    TypeAlias(TypeAliasDef),
+
+    #[ast(kind = "NODE_TYPE_BOUNDS_DEF")]
+    TypeBounds(TypeBoundsDef),
+
+    #[ast(kind = "NODE_PERMISSIVE_DEF")]
+    Permissive(PermissiveDef),
+
+    #[ast(kind = "NODE_CLASS_DEF")]
+    Class(ClassDef),
+
+    #[ast(kind = "NODE_COMMON_DEF")]
+    Common(CommonDef),
+
+    #[ast(kind = "NODE_CONDITIONAL_STMT")]
+    Conditional(ConditionalStmt),
+
+    #[ast(kind = "NODE_SET_MODIFIER_STMT")]
+    SetModifier(SetModifierStmt),
+
+    #[ast(kind = "NODE_MACRO_CALL")]
+    MacroCall(MacroCall),
+
+    #[ast(kind = "NODE_ORDER_STMT")]
+    Order(OrderStmt),
+
+    #[ast(kind = "NODE_IMPORT_STMT")]
+    Import(ImportStmt),
+
+    #[ast(kind = "NODE_ROLE_TYPE_RULE")]
+    RoleType(RoleTypeRule),
+
+    #[ast(kind = "NODE_ROLE_ALLOW_RULE")]
+    RoleAllow(RoleAllowRule),
+
+    #[ast(kind = "NODE_ROLE_TRANSITION_RULE")]
+    RoleTransition(RoleTransitionRule),
+
+    #[ast(kind = "NODE_USER_ROLE_STMT")]
+    UserRole(UserRoleStmt),
+
+    #[ast(kind = "NODE_USER_LEVEL_STMT")]
+    UserLevel(UserLevelStmt),
+
+    #[ast(kind = "NODE_USER_RANGE_STMT")]
+    UserRange(UserRangeStmt),
+
+    #[ast(kind = "NODE_SENSITIVITY_CATEGORY_STMT")]
+    SensitivityCategory(SensitivityCategoryStmt),
+
+    #[ast(kind = "NODE_CONSTRAINT_STMT")]
+    Constraint(ConstraintStmt),
+
+    #[ast(kind = "NODE_FILE_CONTEXT_STMT")]
+    FileContext(FileContextStmt),
+
+    #[ast(kind = "NODE_FS_USE_STMT")]
+    FsUse(FsUseStmt),
+
+    #[ast(kind = "NODE_GENFSCON_STMT")]
+    Genfscon(GenfsconStmt),
+
+    #[ast(kind = "NODE_PORT_CONTEXT_STMT")]
+    PortContext(PortContextStmt),
+
+    #[ast(kind = "NODE_NETIF_CONTEXT_STMT")]
+    NetifContext(NetifContextStmt),
+
+    #[ast(kind = "NODE_NODE_CONTEXT_STMT")]
+    NodeContext(NodeContextStmt),
+
+    #[ast(kind = "NODE_IBPKEY_CONTEXT_STMT")]
+    IbPkeyContext(IbPkeyContextStmt),
+
+    #[ast(kind = "NODE_IBENDPORT_CONTEXT_STMT")]
+    IbEndportContext(IbEndportContextStmt),
+
+    #[ast(kind = "NODE_SID_CONTEXT_STMT")]
+    SidContext(SidContextStmt),
+
+    #[ast(kind = "NODE_TE_RULE")]
+    TeRule(TeRule),
+
+    #[ast(kind = "NODE_XPERM_RULE")]
+    XpermRule(XpermRule),
+
+    #[ast(kind = "NODE_TYPE_TRANSITION_RULE")]
+    TypeTransition(TypeTransitionRule),
+
+    #[ast(kind = "NODE_TYPE_CHANGE_RULE")]
+    TypeChange(TypeChangeRule),
+
+    #[ast(kind = "NODE_TYPE_MEMBER_RULE")]
+    TypeMember(TypeMemberRule),
+}
+
+/// Any expression within the condition of a constraint.
+#[derive(AstEnum, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ConstraintExpr {
+    #[ast(kind = "NODE_CONSTRAINT_BINARY_EXPR")]
+    Binary(ConstraintBinaryExpr),
+
+    #[ast(kind = "NODE_CONSTRAINT_COMPARISON_EXPR")]
+    Comparison(ConstraintComparisonExpr),
+
+    #[ast(kind = "NODE_CONSTRAINT_NOT_EXPR")]
+    Not(ConstraintNotExpr),
+
+    #[ast(kind = "NODE_CONSTRAINT_PAREN_EXPR")]
+    Paren(ConstraintParenExpr),
+}
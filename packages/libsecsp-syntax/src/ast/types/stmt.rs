@@ -1,2 +1,124 @@
+use secsp_parser::syntax::{KeywordKind, SyntaxKind};
+
+use crate::ast::types::*;
+use crate::ast::{find_token, AstNode};
+
+pub use self::constraint::*;
+pub use self::type_enforcement::*;
+
+mod constraint;
 mod labeling;
+mod mls;
+mod rbac;
 mod type_enforcement;
+
+impl MacroCall {
+    pub fn arguments(&self) -> impl Iterator<Item = MacroArgument> {
+        self.argument_list()
+            .into_iter()
+            .flat_map(|list| list.children())
+    }
+}
+
+/// The operator of a [SetModifierStmt].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SetModifierOp {
+    /// Add members to a set: `|=`.
+    Add,
+    /// Remove members from a set: `-=`.
+    Remove,
+}
+
+impl SetModifierStmt {
+    pub fn op(&self) -> Option<SetModifierOp> {
+        find_token(self.syntax(), |tok| match tok.kind() {
+            SyntaxKind::TOK_PIPE_EQUALS => Some(SetModifierOp::Add),
+            SyntaxKind::TOK_HYPHEN_EQUALS => Some(SetModifierOp::Remove),
+            _ => None,
+        })
+    }
+}
+
+impl OrderStmt {
+    pub fn order_kind(&self) -> Option<KeywordKind> {
+        find_token(self.syntax(), |tok| {
+            KeywordKind::from_syntax_kind(tok.kind())
+        })
+    }
+}
+
+impl ImportStmt {
+    /// Get the path of the imported file, without the surrounding quotes.
+    pub fn target(&self) -> Option<String> {
+        self.path()?.unescape()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::testing::parse_and_find;
+
+    use super::*;
+
+    #[test]
+    fn macro_call_path_and_arguments() {
+        let call: MacroCall = parse_and_find("a.b(c, \"d\", 1);");
+
+        assert_eq!("a.b", call.path().expect("no path").path());
+        assert_eq!(3, call.arguments().count());
+    }
+
+    #[test]
+    fn macro_call_named_arguments() {
+        let call: MacroCall = parse_and_find("m(a, write = true);");
+        let arguments: Vec<_> = call
+            .arguments()
+            .map(|argument| (argument.name(), argument.value().is_some()))
+            .collect();
+
+        assert_eq!(
+            vec![(None, true), (Some("write".to_string()), true)],
+            arguments
+        );
+    }
+
+    #[test]
+    fn conditional_with_else_if() {
+        let cond: ConditionalStmt = parse_and_find("if a { type t; } else if b {} else {}");
+        let else_if = cond.else_if().expect("no else-if branch");
+
+        assert!(cond.condition().is_some());
+        assert_eq!(1, cond.then_block().expect("no block").items().count());
+        assert!(cond.else_block().is_none());
+        assert!(else_if.else_block().is_some());
+        assert!(else_if.else_if().is_none());
+    }
+
+    #[test]
+    fn set_modifier_operands() {
+        let stmt: SetModifierStmt = parse_and_find("a.b -= c | d;");
+
+        assert_eq!("a.b", stmt.target().expect("no target").path());
+        assert_eq!(Some(SetModifierOp::Remove), stmt.op());
+
+        match stmt.value() {
+            Some(Expr::Binary(_)) => {}
+            e => panic!("unexpected value: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn order_items() {
+        let stmt: OrderStmt = parse_and_find("class_order (file);");
+
+        assert_eq!(Some(KeywordKind::ClassOrder), stmt.order_kind());
+        assert_eq!(1, stmt.items().expect("no items").items().count());
+    }
+
+    #[test]
+    fn import_target() {
+        let stmt: ImportStmt = parse_and_find("import \"types/base.csp\";");
+
+        assert_eq!(Some("types/base.csp".to_string()), stmt.target());
+    }
+}
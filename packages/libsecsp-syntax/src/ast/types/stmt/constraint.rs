@@ -0,0 +1,100 @@
+use secsp_parser::syntax::{KeywordKind, SyntaxKind};
+
+use crate::ast::types::*;
+use crate::ast::{find_token, AstNode};
+
+impl ConstraintStmt {
+    pub fn constraint_kind(&self) -> Option<KeywordKind> {
+        find_token(self.syntax(), |tok| {
+            KeywordKind::from_syntax_kind(tok.kind())
+        })
+    }
+
+    /// Check if this constraint applies to the levels of a multi-level security policy.
+    pub fn is_mls(&self) -> bool {
+        match self.constraint_kind() {
+            Some(kind) => {
+                [KeywordKind::MlsConstrain, KeywordKind::MlsValidateTrans].contains(&kind)
+            }
+            None => false,
+        }
+    }
+}
+
+/// The operator of a [ConstraintComparisonExpr].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConstraintOp {
+    /// `==`
+    Eq,
+    /// `!=`
+    Neq,
+    /// `dom`
+    Dom,
+    /// `domby`
+    DomBy,
+    /// `incomp`
+    Incomp,
+}
+
+impl ConstraintBinaryExpr {
+    pub fn op(&self) -> Option<BinaryOp> {
+        find_token(self.syntax(), |tok| match tok.kind() {
+            SyntaxKind::TOK_DOUBLE_AMPERSAND => Some(BinaryOp::LogicalAnd),
+            SyntaxKind::TOK_DOUBLE_PIPE => Some(BinaryOp::LogicalOr),
+            _ => None,
+        })
+    }
+}
+
+impl ConstraintComparisonExpr {
+    pub fn op(&self) -> Option<ConstraintOp> {
+        find_token(self.syntax(), |tok| match tok.kind() {
+            SyntaxKind::TOK_DOUBLE_EQUALS => Some(ConstraintOp::Eq),
+            SyntaxKind::TOK_EXCLAMATION_EQUALS => Some(ConstraintOp::Neq),
+            SyntaxKind::KW_DOM => Some(ConstraintOp::Dom),
+            SyntaxKind::KW_DOMBY => Some(ConstraintOp::DomBy),
+            SyntaxKind::KW_INCOMP => Some(ConstraintOp::Incomp),
+            _ => None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::testing::parse_and_find;
+
+    #[test]
+    fn constraint_operands() {
+        let stmt: ConstraintStmt =
+            parse_and_find("mls_constrain file (read) l1 dom h2 && !(t1 == (a b));");
+
+        assert_eq!(Some(KeywordKind::MlsConstrain), stmt.constraint_kind());
+        assert!(stmt.is_mls());
+
+        match stmt.access_vector() {
+            Some(Expr::Set(_)) => {}
+            e => panic!("unexpected access vector: {:?}", e),
+        }
+
+        let binary = match stmt.expr() {
+            Some(ConstraintExpr::Binary(binary)) => binary,
+            e => panic!("unexpected constraint expression: {:?}", e),
+        };
+
+        assert_eq!(Some(BinaryOp::LogicalAnd), binary.op());
+
+        match binary.lhs() {
+            Some(ConstraintExpr::Comparison(comparison)) => {
+                assert_eq!("l1", comparison.lhs().expect("no lhs").path());
+                assert_eq!(Some(ConstraintOp::Dom), comparison.op());
+            }
+            e => panic!("unexpected lhs: {:?}", e),
+        }
+
+        match binary.rhs() {
+            Some(ConstraintExpr::Not(_)) => {}
+            e => panic!("unexpected rhs: {:?}", e),
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use secsp_parser::syntax::KeywordKind;
+
+use crate::ast::types::*;
+use crate::ast::{find_token, AstNode};
+
+impl FileContextStmt {
+    /// Get the type of file being labeled, if the statement is restricted to one.
+    pub fn file_type(&self) -> Option<PathExpr> {
+        if self.has_file_type() {
+            self.children().next()
+        } else {
+            None
+        }
+    }
+
+    pub fn context(&self) -> Option<Expr> {
+        let offset = if self.has_file_type() { 2 } else { 1 };
+        self.children().nth(offset)
+    }
+
+    fn has_file_type(&self) -> bool {
+        self.children::<Expr>().count() == 3
+    }
+}
+
+impl FsUseStmt {
+    pub fn fs_use_kind(&self) -> Option<KeywordKind> {
+        find_token(self.syntax(), |tok| {
+            KeywordKind::from_syntax_kind(tok.kind())
+        })
+    }
+}
+
+impl PortContextStmt {
+    pub fn context(&self) -> Option<Expr> {
+        self.children()
+            .skip(1)
+            .find(|expr| !matches!(expr, Expr::Literal(_)))
+    }
+}
+
+impl NodeContextStmt {
+    pub fn context(&self) -> Option<Expr> {
+        self.children()
+            .find(|expr| !matches!(expr, Expr::Literal(_)))
+    }
+}
+
+impl IbPkeyContextStmt {
+    pub fn context(&self) -> Option<Expr> {
+        self.children()
+            .find(|expr| !matches!(expr, Expr::Literal(_)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::testing::parse_and_find;
+    use crate::ast::types::LiteralKind;
+
+    #[test]
+    fn file_context_with_file_type() {
+        let stmt: FileContextStmt = parse_and_find("file_context \"/tmp\" dir u:r:t;");
+
+        assert_eq!("\"/tmp\"", stmt.path().expect("no path").text());
+        assert_eq!("dir", stmt.file_type().expect("no file type").path());
+
+        match stmt.context() {
+            Some(Expr::Context(_)) => {}
+            e => panic!("unexpected context: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn file_context_without_file_type() {
+        let stmt: FileContextStmt = parse_and_find("file_context \"/tmp\" u:r:t;");
+
+        assert!(stmt.file_type().is_none());
+        assert!(stmt.context().is_some());
+    }
+
+    #[test]
+    fn file_context_with_path_pattern() {
+        let stmt: FileContextStmt = parse_and_find("file_context /usr/bin(/.*)? u:r:t;");
+        let path = stmt.path().expect("no path");
+
+        assert_eq!(Some(LiteralKind::PathPattern), path.kind());
+        assert_eq!("/usr/bin(/.*)?", path.text());
+        assert!(stmt.file_type().is_none());
+    }
+
+    #[test]
+    fn fs_use_operands() {
+        let stmt: FsUseStmt = parse_and_find("fs_use_task pipefs u:r:t;");
+
+        assert_eq!(Some(KeywordKind::FsUseTask), stmt.fs_use_kind());
+        assert_eq!("pipefs", stmt.fs_type().expect("no fs type").path());
+        assert!(stmt.context().is_some());
+    }
+
+    #[test]
+    fn genfscon_operands() {
+        let stmt: GenfsconStmt = parse_and_find("genfscon proc \"/\" u:r:t;");
+
+        assert_eq!("proc", stmt.fs_type().expect("no fs type").path());
+        assert_eq!("\"/\"", stmt.path().expect("no path").text());
+        assert!(stmt.context().is_some());
+    }
+
+    #[test]
+    fn port_context_range() {
+        let stmt: PortContextStmt = parse_and_find("port_context tcp 8000-8080 u:r:t;");
+
+        assert_eq!("tcp", stmt.protocol().expect("no protocol").path());
+        assert_eq!("8000", stmt.low_port().expect("no port").text());
+        assert_eq!("8080", stmt.high_port().expect("no high port").text());
+
+        match stmt.context() {
+            Some(Expr::Context(_)) => {}
+            e => panic!("unexpected context: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn node_context_addresses() {
+        let stmt: NodeContextStmt = parse_and_find("node_context fe80:: ffff:ffff:: u:r:t;");
+        let address = stmt.address().expect("no address");
+
+        assert_eq!("fe80::", address.text());
+        assert_eq!(Some(LiteralKind::Ipv6Address), address.kind());
+        assert_eq!("ffff:ffff::", stmt.mask().expect("no mask").text());
+        assert!(stmt.context().is_some());
+    }
+
+    #[test]
+    fn sid_context_operands() {
+        let stmt: SidContextStmt = parse_and_find("sid_context kernel u:r:t:s0;");
+
+        assert_eq!("kernel", stmt.sid().expect("no sid").path());
+
+        match stmt.context() {
+            Some(Expr::Context(_)) => {}
+            e => panic!("unexpected context: {:?}", e),
+        }
+    }
+}
@@ -0,0 +1,17 @@
+#[cfg(test)]
+mod tests {
+    use crate::ast::testing::parse_and_find;
+    use crate::ast::types::*;
+
+    #[test]
+    fn test_sensitivity_category() {
+        let stmt: SensitivityCategoryStmt = parse_and_find("sensitivity_category s0 c0..c5;");
+
+        assert_eq!("s0", stmt.sensitivity().expect("no sensitivity").path());
+
+        match stmt.categories() {
+            Some(Expr::CategoryRange(_)) => {}
+            e => panic!("unexpected categories: {:?}", e),
+        }
+    }
+}
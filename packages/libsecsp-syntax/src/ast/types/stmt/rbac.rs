@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod tests {
+    use crate::ast::testing::parse_and_find;
+    use crate::ast::types::*;
+
+    fn path(expr: Option<Expr>) -> String {
+        match expr {
+            Some(Expr::Path(path)) => path.path(),
+            e => panic!("unexpected expression: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn test_role_type() {
+        let rule: RoleTypeRule = parse_and_find("role_type r t;");
+
+        assert_eq!("r", path(rule.role()));
+        assert_eq!("t", path(rule.types()));
+    }
+
+    #[test]
+    fn test_role_transition() {
+        let rule: RoleTransitionRule = parse_and_find("role_transition a b : process c;");
+
+        assert_eq!("a", path(rule.source()));
+        assert_eq!("b", path(rule.target()));
+        assert_eq!("process", path(rule.class()));
+        assert_eq!("c", path(rule.result_role()));
+    }
+
+    #[test]
+    fn test_user_level() {
+        let stmt: UserLevelStmt = parse_and_find("user_level u s0:c0;");
+
+        assert_eq!("u", path(stmt.user()));
+
+        match stmt.level() {
+            Some(Expr::Level(_)) => {}
+            e => panic!("unexpected level: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn test_user_range() {
+        let stmt: UserRangeStmt = parse_and_find("user_range u s0-s1:c0..c5;");
+
+        match stmt.range() {
+            Some(Expr::LevelRange(_)) => {}
+            e => panic!("unexpected range: {:?}", e),
+        }
+    }
+}
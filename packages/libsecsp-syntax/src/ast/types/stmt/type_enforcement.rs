@@ -1,13 +1,9 @@
-use crate::ast::AstNode;
+use crate::ast::types::*;
+use crate::ast::{find_token, AstNode};
 
-use secsp_parser::syntax::{SyntaxKind, SyntaxNode};
-use secsp_syntax_derive::AstType;
+use secsp_parser::syntax::{KeywordKind, SyntaxKind};
 
-#[derive(AstType)]
-#[ast(kind = "NODE_TE_RULE")]
-pub struct TeRule(SyntaxNode);
-
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum TeRuleKind {
     Allow,
     AuditAllow,
@@ -16,7 +12,7 @@ pub enum TeRuleKind {
 }
 
 impl TeRule {
-    fn rule_kind(&self) -> TeRuleKind {
+    pub fn rule_kind(&self) -> TeRuleKind {
         self.syntax()
             .children_with_tokens()
             .find_map(|child| {
@@ -38,6 +34,50 @@ impl TeRule {
     }
 }
 
+impl XpermRule {
+    /// Get the kind of operation that the numbers of this rule select, e.g. `ioctl`.
+    pub fn operation_kind(&self) -> Option<KeywordKind> {
+        find_token(self.syntax(), |tok| match tok.kind() {
+            SyntaxKind::KW_IOCTL => Some(KeywordKind::Ioctl),
+            _ => None,
+        })
+    }
+
+    pub fn rule_kind(&self) -> TeRuleKind {
+        find_token(self.syntax(), |tok| match tok.kind() {
+            SyntaxKind::KW_ALLOW_XPERM => Some(TeRuleKind::Allow),
+            SyntaxKind::KW_AUDIT_ALLOW_XPERM => Some(TeRuleKind::AuditAllow),
+            SyntaxKind::KW_DONT_AUDIT_XPERM => Some(TeRuleKind::DontAudit),
+            SyntaxKind::KW_NEVER_ALLOW_XPERM => Some(TeRuleKind::NeverAllow),
+            _ => None,
+        })
+        .expect("XpermRule nodes must have a rule keyword token")
+    }
+}
+
+/// The operands shared by the rules that compute a new type for a `source` and `target` pair.
+pub trait TypeRule: AstNode {
+    fn source(&self) -> Option<Expr> {
+        self.children().next()
+    }
+
+    fn target(&self) -> Option<Expr> {
+        self.children().nth(1)
+    }
+
+    /// Get the class of object that this rule applies to.
+    fn class(&self) -> Option<Expr> {
+        self.children().nth(2)
+    }
+
+    /// Get the type computed by this rule.
+    fn result_type(&self) -> Option<Expr> {
+        self.children()
+            .skip(3)
+            .find(|expr| !matches!(expr, Expr::Literal(_)))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -68,4 +108,64 @@ mod tests {
     fn test_never_allow() {
         test_rule_kind(TeRuleKind::NeverAllow, "never_allow src dest : perms;");
     }
+
+    #[test]
+    fn test_rule_operands() {
+        let rule: TeRule = parse_and_find("allow src dest : file (read write);");
+
+        assert!(rule.source().is_some());
+        assert!(rule.target().is_some());
+
+        match rule.access_vector() {
+            Some(Expr::Set(_)) => {}
+            e => panic!("unexpected access vector: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn test_xperm_rule() {
+        let rule: XpermRule = parse_and_find(
+            "never_allow_xperm src dest : tcp_socket ioctl (0x8900, 0x8910-0x891f);",
+        );
+
+        assert_eq!(TeRuleKind::NeverAllow, rule.rule_kind());
+        assert_eq!(Some(KeywordKind::Ioctl), rule.operation_kind());
+        assert!(rule.class().is_some());
+
+        let ranges: Vec<_> = rule.ranges().collect();
+
+        assert_eq!(2, ranges.len());
+        assert_eq!("0x8900", ranges[0].low().expect("no low").text());
+        assert!(ranges[0].high().is_none());
+        assert_eq!("0x891f", ranges[1].high().expect("no high").text());
+    }
+
+    #[test]
+    fn test_named_type_transition() {
+        let rule: TypeTransitionRule =
+            parse_and_find("type_transition src dest : file \"name\" result;");
+
+        assert!(rule.source().is_some());
+        assert!(rule.target().is_some());
+        assert!(rule.class().is_some());
+        assert_eq!(
+            "\"name\"",
+            rule.object_name().expect("no object name").text()
+        );
+
+        match rule.result_type() {
+            Some(Expr::Path(path)) => assert_eq!("result", path.path()),
+            e => panic!("unexpected result type: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn test_type_change() {
+        let rule: TypeChangeRule = parse_and_find("type_change src dest : file result;");
+
+        match rule.result_type() {
+            Some(Expr::Path(path)) => assert_eq!("result", path.path()),
+            e => panic!("unexpected result type: {:?}", e),
+        }
+    }
 }
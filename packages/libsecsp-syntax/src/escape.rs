@@ -0,0 +1,52 @@
+//! Escape sequences within string literals. A backslash escapes a quote or another backslash,
+//! and any other escape sequence is invalid.
+
+use std::ops::Range;
+
+/// Unescape the contents of a string literal, between its quotes. Calls [on_error] with the
+/// range of each invalid escape sequence, which is kept in the result as it was written.
+pub(crate) fn unescape(text: &str, mut on_error: impl FnMut(Range<usize>)) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.char_indices();
+
+    while let Some((pos, ch)) = chars.next() {
+        if ch != '\\' {
+            result.push(ch);
+            continue;
+        }
+
+        match chars.next() {
+            Some((_, escaped @ '\\')) | Some((_, escaped @ '"')) => result.push(escaped),
+            Some((escaped_pos, escaped)) => {
+                on_error(pos..escaped_pos + escaped.len_utf8());
+                result.push(ch);
+                result.push(escaped);
+            }
+            None => {
+                on_error(pos..pos + 1);
+                result.push(ch);
+            }
+        }
+    }
+
+    result
+}
+
+#[test]
+fn unescapes_quotes_and_backslashes() {
+    let mut errors = vec![];
+
+    assert_eq!(
+        r#"a"b\c"#,
+        unescape(r#"a\"b\\c"#, |range| errors.push(range))
+    );
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn reports_invalid_escapes() {
+    let mut errors = vec![];
+
+    assert_eq!(r"\.so\é", unescape(r"\.so\é", |range| errors.push(range)));
+    assert_eq!(vec![0..2, 4..7], errors);
+}
@@ -10,10 +10,14 @@ extern crate text_unit;
 
 pub use ast::SourceFile;
 pub use parsing::Parse;
+pub use secsp_parser::ParseError;
+pub use text_edit::TextEdit;
 
 pub mod ast;
 
+mod escape;
 mod parsing;
+mod text_edit;
 mod token;
 
 impl SourceFile {
@@ -1,17 +1,24 @@
 use std::marker::PhantomData;
+use std::ops::Range;
 use std::sync::Arc;
 
 use rowan::GreenNode;
 
-use secsp_parser::syntax::SyntaxNode;
+use text_unit::{TextRange, TextUnit};
+
+use secsp_parser::syntax::{block_comment_len, SyntaxKind, SyntaxNode};
 use secsp_parser::ParseError;
 
 use crate::ast::AstNode;
+use crate::escape;
+use crate::text_edit::TextEdit;
+use crate::token::Token;
 use crate::SourceFile;
 
 use self::text_token_source::TextTokenSource;
 use self::text_tree_sink::TextTreeSink;
 
+mod reparsing;
 #[cfg(test)]
 mod tests;
 mod text_token_source;
@@ -33,6 +40,20 @@ impl<T: AstNode> Parse<T> {
     pub fn syntax_node(&self) -> SyntaxNode {
         SyntaxNode::new_root(self.green.clone())
     }
+
+    /// Get the list of errors that were encountered while parsing, in the order they occurred.
+    pub fn errors(&self) -> &[ParseError] {
+        &self.errors
+    }
+}
+
+impl Parse<SourceFile> {
+    /// Apply [edit] to the parsed text, reparsing only the part of the tree that it affects
+    /// when it can be isolated.
+    pub fn reparse(&self, edit: &TextEdit) -> Parse<SourceFile> {
+        reparsing::incremental_reparse(self, edit)
+            .unwrap_or_else(|| parse_text(edit.apply(&self.syntax_node().text().to_string())))
+    }
 }
 
 pub fn parse_text<T>(text: T) -> Parse<SourceFile>
@@ -45,7 +66,9 @@ where
     let mut tree_sink = TextTreeSink::new(text, &tokens);
     secsp_parser::parse_file(&token_source, &mut tree_sink);
 
-    let (green, errors) = tree_sink.finish();
+    let (green, mut errors) = tree_sink.finish();
+    errors.extend(lexical_errors(text, &tokens));
+    errors.sort_by_key(|error| error.range().start());
 
     Parse {
         green,
@@ -53,3 +76,50 @@ where
         _ty: PhantomData,
     }
 }
+
+/// Find the errors within the tokens of [text] that the parser doesn't report, such as
+/// unterminated comments and strings, positioned relative to the start of [text].
+pub(crate) fn lexical_errors(text: &str, tokens: &[Token]) -> Vec<ParseError> {
+    let mut errors = vec![];
+    let to_text_range = |range: Range<usize>| {
+        TextRange::from_to(
+            TextUnit::from_usize(range.start),
+            TextUnit::from_usize(range.end),
+        )
+    };
+
+    for token in tokens {
+        let range = token.range();
+        let token_text = &text[range.clone()];
+
+        match token.kind() {
+            SyntaxKind::TOK_BLOCK_COMMENT if block_comment_len(token_text.as_bytes()).is_none() => {
+                errors.push(
+                    ParseError::new("unterminated block comment", vec![], token.kind())
+                        .with_range(to_text_range(range)),
+                );
+            }
+            SyntaxKind::TOK_UNTERMINATED_STRING => {
+                errors.push(
+                    ParseError::new("unterminated string", vec![], token.kind())
+                        .with_range(to_text_range(range)),
+                );
+            }
+            SyntaxKind::TOK_STRING => {
+                // Escapes are positioned relative to the text after the opening quote.
+                let contents = &token_text[1..token_text.len() - 1];
+                let offset = range.start + 1;
+
+                escape::unescape(contents, |escape| {
+                    errors.push(
+                        ParseError::new("invalid escape sequence", vec![], token.kind())
+                            .with_range(to_text_range(escape.start + offset..escape.end + offset)),
+                    )
+                });
+            }
+            _ => {}
+        }
+    }
+
+    errors
+}
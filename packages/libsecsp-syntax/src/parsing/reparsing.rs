@@ -0,0 +1,278 @@
+//! Incremental reparsing of a source file after a [TextEdit].
+//!
+//! An edit within a single comment, string or run of whitespace only relexes that token. Any
+//! other edit reparses the smallest block surrounding it whose braces are still balanced
+//! afterwards. Edits that can't be contained by either are left to a full reparse.
+
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use rowan::{GreenNode, GreenToken, SmolStr};
+use text_unit::{TextRange, TextUnit};
+
+use secsp_parser::syntax::{
+    block_comment_len, SyntaxElement, SyntaxKind, SyntaxNode, SyntaxToken, TokenKind,
+};
+use secsp_parser::ParseError;
+
+use crate::parsing::text_token_source::TextTokenSource;
+use crate::parsing::text_tree_sink::TextTreeSink;
+use crate::parsing::{lexical_errors, tokenizer, Parse};
+use crate::text_edit::TextEdit;
+use crate::token::Token;
+use crate::SourceFile;
+
+/// A reparsed subtree spliced into the tree, with the errors found while reparsing it.
+struct Reparse {
+    green: GreenNode,
+    errors: Vec<ParseError>,
+    /// The range of the original text that errors were replaced within.
+    replaced: TextRange,
+}
+
+/// Apply [edit] to [parse] by reparsing as little of the tree as possible, returning `None` if
+/// the edit needs a full reparse.
+pub(crate) fn incremental_reparse(
+    parse: &Parse<SourceFile>,
+    edit: &TextEdit,
+) -> Option<Parse<SourceFile>> {
+    let root = parse.syntax_node();
+    let reparse =
+        reparse_token(&root, parse.errors(), edit).or_else(|| reparse_block(&root, edit))?;
+
+    let mut errors: Vec<ParseError> = parse
+        .errors()
+        .iter()
+        .filter(|error| !reparse.replaced.contains(error.range().start()))
+        .map(|error| {
+            let range = error.range();
+            error.clone().with_range(TextRange::offset_len(
+                edit.shift(range.start()),
+                range.len(),
+            ))
+        })
+        .collect();
+
+    errors.extend(reparse.errors);
+    errors.sort_by_key(|error| error.range().start());
+
+    Some(Parse {
+        green: reparse.green,
+        errors: Arc::new(errors),
+        _ty: PhantomData,
+    })
+}
+
+fn reparse_token(root: &SyntaxNode, errors: &[ParseError], edit: &TextEdit) -> Option<Reparse> {
+    let token = root.covering_element(edit.range()).into_token()?;
+    let range = token.text_range();
+
+    if !edit.range().is_subrange(&range) || !is_relexable(token.kind()) {
+        return None;
+    }
+
+    let old_text = token.text().as_str();
+    let new_text = edit.apply_at(range.start(), old_text);
+    let tokens = tokenizer::tokenize(&new_text);
+
+    // The new text has to lex as the same single token, followed by the end of input.
+    match tokens.as_slice() {
+        [new_token, _]
+            if new_token.kind() == token.kind()
+                && new_token.len() == TextUnit::of_str(&new_text) => {}
+        _ => return None,
+    }
+
+    let preserves_tree = match token.kind() {
+        // A blank line stops doc comments from being attached to the following definition, and
+        // line comments and unterminated strings have to be followed by the newline that ends
+        // them.
+        SyntaxKind::TOK_WHITESPACE => {
+            has_blank_line(old_text) == has_blank_line(&new_text)
+                && (new_text.starts_with('\n') || !follows_line_end_token(&token))
+        }
+        SyntaxKind::TOK_BLOCK_COMMENT => {
+            is_terminated_comment(old_text) && is_terminated_comment(&new_text)
+        }
+        _ => true,
+    };
+
+    if !preserves_tree {
+        return None;
+    }
+
+    let new_range = TextRange::offset_len(range.start(), TextUnit::of_str(&new_text));
+    let green = token.replace_with(GreenToken::new(
+        token.kind().into(),
+        SmolStr::new(&new_text),
+    ));
+
+    // Strings can be the token an error was reported against, so those errors move with it,
+    // while errors within the string are found again in the new text.
+    let errors = errors
+        .iter()
+        .filter(|error| error.range() == range)
+        .map(|error| error.clone().with_range(new_range))
+        .chain(lexical_errors(&new_text, &tokens).into_iter().map(|error| {
+            let range = error.range() + range.start();
+            error.with_range(range)
+        }))
+        .collect();
+
+    Some(Reparse {
+        green,
+        errors,
+        replaced: range,
+    })
+}
+
+fn reparse_block(root: &SyntaxNode, edit: &TextEdit) -> Option<Reparse> {
+    let ancestors = match root.covering_element(edit.range()) {
+        SyntaxElement::Node(node) => node.ancestors(),
+        SyntaxElement::Token(token) => token.parent().ancestors(),
+    };
+
+    ancestors
+        .filter(|node| node.kind() == SyntaxKind::NODE_BLOCK)
+        .find_map(|block| reparse_block_node(&block, edit))
+}
+
+fn reparse_block_node(block: &SyntaxNode, edit: &TextEdit) -> Option<Reparse> {
+    let (open, close) = braces(block)?;
+
+    // The edit has to be between the braces, so that both of them survive it.
+    if edit.range().start() < open.text_range().end()
+        || edit.range().end() > close.text_range().start()
+    {
+        return None;
+    }
+
+    let offset = block.text_range().start();
+    let new_text = edit.apply_at(offset, &block.text().to_string());
+    let new_len = TextUnit::of_str(&new_text);
+    let tokens = tokenizer::tokenize(&new_text);
+
+    if !is_balanced(&tokens) {
+        return None;
+    }
+
+    let token_source = TextTokenSource::new(&new_text, &tokens);
+    let mut tree_sink = TextTreeSink::new(&new_text, &tokens);
+    // The depth of the block affects how deeply nodes within it can be nested.
+    let depth = block.ancestors().skip(1).count();
+    secsp_parser::parse_block(&token_source, &mut tree_sink, depth);
+
+    let (green, mut errors) = tree_sink.finish();
+    errors.extend(lexical_errors(&new_text, &tokens));
+
+    // Errors reported against the end of the input refer to whatever follows the block in the
+    // file, so they can't be placed without parsing the rest of it.
+    if green.kind() != SyntaxKind::NODE_BLOCK.into()
+        || green.text_len() != new_len
+        || errors.iter().any(|error| error.range().start() >= new_len)
+    {
+        return None;
+    }
+
+    let errors = errors
+        .into_iter()
+        .map(|error| {
+            let range = error.range();
+            error.with_range(TextRange::offset_len(range.start() + offset, range.len()))
+        })
+        .collect();
+
+    Some(Reparse {
+        green: block.replace_with(green),
+        errors,
+        replaced: TextRange::from_to(open.text_range().end(), close.text_range().end()),
+    })
+}
+
+fn is_relexable(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::TOK_WHITESPACE
+            | SyntaxKind::TOK_LINE_COMMENT
+            | SyntaxKind::TOK_DOC_COMMENT
+            | SyntaxKind::TOK_BLOCK_COMMENT
+            | SyntaxKind::TOK_STRING
+            | SyntaxKind::TOK_PATH_PATTERN
+    )
+}
+
+fn has_blank_line(text: &str) -> bool {
+    text.matches('\n').count() > 1
+}
+
+/// Check if [token] follows a token that runs to the end of its line.
+fn follows_line_end_token(token: &SyntaxToken) -> bool {
+    matches!(
+        token.prev_token().map(|prev| prev.kind()),
+        Some(SyntaxKind::TOK_LINE_COMMENT)
+            | Some(SyntaxKind::TOK_DOC_COMMENT)
+            | Some(SyntaxKind::TOK_UNTERMINATED_STRING)
+    )
+}
+
+fn is_terminated_comment(text: &str) -> bool {
+    block_comment_len(text.as_bytes()) == Some(text.len())
+}
+
+/// Find the opening and closing braces of a block, if it has both.
+fn braces(block: &SyntaxNode) -> Option<(SyntaxToken, SyntaxToken)> {
+    let open = block
+        .children_with_tokens()
+        .find(|child| !is_trivia(child.kind()))?
+        .into_token()
+        .filter(|token| token.kind() == SyntaxKind::TOK_OPEN_BRACE)?;
+    let close = block
+        .last_child_or_token()?
+        .into_token()
+        .filter(|token| token.kind() == SyntaxKind::TOK_CLOSE_BRACE)?;
+
+    Some((open, close))
+}
+
+fn is_trivia(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::TOK_WHITESPACE
+            | SyntaxKind::TOK_LINE_COMMENT
+            | SyntaxKind::TOK_DOC_COMMENT
+            | SyntaxKind::TOK_BLOCK_COMMENT
+    )
+}
+
+/// Check that relexed block text is still a single block: an opening brace, followed by
+/// nothing that closes it before the final closing brace.
+fn is_balanced(tokens: &[Token]) -> bool {
+    let significant: Vec<TokenKind> = tokens
+        .iter()
+        .filter(|token| !token.is_trivia())
+        .map(|&token| token.into())
+        .take_while(|&kind| kind != TokenKind::Eof)
+        .collect();
+
+    if significant.first() != Some(&TokenKind::OpenBrace) {
+        return false;
+    }
+
+    let mut depth = 0;
+
+    for (idx, kind) in significant.iter().enumerate() {
+        match kind {
+            TokenKind::OpenBrace => depth += 1,
+            TokenKind::CloseBrace => depth -= 1,
+            // Illegal tokens can be the start of a string or comment that ends after the block.
+            TokenKind::Illegal => return false,
+            _ => {}
+        }
+
+        if depth == 0 {
+            return idx == significant.len() - 1;
+        }
+    }
+
+    false
+}
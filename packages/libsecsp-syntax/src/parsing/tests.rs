@@ -10,7 +10,10 @@ use crate::ast::AstNode;
 use crate::SourceFile;
 
 mod def;
+mod errors;
 mod expr;
+mod recovery;
+mod reparse;
 mod stmt;
 
 #[derive(Debug)]
@@ -42,12 +45,35 @@ pub fn ast_to_string(source: &SyntaxNode) -> String {
 
 pub(crate) fn test_parser(text: &str) {
     let (code, assertions) = strip_markers(0.into(), text);
+    let parse = SourceFile::parse(code.as_str());
 
+    assert!(
+        parse.errors().is_empty(),
+        "Unexpected parse errors: {:#?}",
+        parse.errors()
+    );
+
+    check_assertions(&parse.tree(), assertions);
+}
+
+/// Parse text containing errors, checking that exactly the [expected_errors] are reported and
+/// that the marked nodes are recovered.
+pub(crate) fn test_recovery(text: &str, expected_errors: &[&str]) {
+    let (code, assertions) = strip_markers(0.into(), text);
+    let parse = SourceFile::parse(code.as_str());
+    let errors: Vec<&str> = parse.errors().iter().map(|error| error.message()).collect();
+
+    assert_eq!(expected_errors, errors.as_slice(), "{:#?}", parse.errors());
+    assert_eq!(code, parse.syntax_node().text().to_string());
+
+    check_assertions(&parse.tree(), assertions);
+}
+
+fn check_assertions(ast: &SourceFile, assertions: Vec<Assertion>) {
     if assertions.is_empty() {
         panic!("No assertions found");
     }
 
-    let ast = SourceFile::parse(code.as_str()).tree();
     let ws_regex = Regex::new(r#"\s"#).unwrap();
 
     for assertion in assertions.into_iter() {
@@ -20,6 +20,32 @@ fn parse_macro_def() {
     )
 }
 
+#[test]
+fn parse_macro_def_with_default_value() {
+    super::test_parser(
+        r#"
+        macro test(type t, <marker type="NODE_MACRO_PARAM_LIST_ITEM">bool b = true</marker>) {
+        }
+    "#,
+    )
+}
+
+#[test]
+fn parse_macro_def_with_param_types() {
+    super::test_parser(
+        r#"
+        macro test(
+            category_set cats,
+            <marker type="NODE_MACRO_PARAM_LIST_ITEM">level l</marker>,
+            ip_address ip,
+            string s,
+            name n
+        ) {
+        }
+    "#,
+    )
+}
+
 #[test]
 #[ignore]
 fn parse_abstract_container_def() {
@@ -56,3 +82,102 @@ fn parse_var_with_initializer() {
     "#,
     )
 }
+
+#[test]
+fn parse_common_def() {
+    super::test_parser(
+        r#"
+        <marker type="NODE_COMMON_DEF">common file <marker type="NODE_LIST_EXPR">(read)</marker>;</marker>
+    "#,
+    )
+}
+
+#[test]
+fn parse_class_def_with_common() {
+    super::test_parser(
+        r#"
+        <marker type="NODE_CLASS_DEF">class file <marker type="NODE_EXTENDS_LIST">extends file</marker>;</marker>
+    "#,
+    )
+}
+
+#[test]
+fn parse_class_permission_def() {
+    super::test_parser(
+        r#"
+        <marker type="NODE_VARIABLE_DEF">class_permission read_file = file (read getattr);</marker>
+    "#,
+    )
+}
+
+#[test]
+fn parse_category_def() {
+    super::test_parser(
+        r#"
+        <marker type="NODE_VARIABLE_DEF">category c0;</marker>
+    "#,
+    )
+}
+
+#[test]
+fn parse_sensitivity_alias_def() {
+    super::test_parser(
+        r#"
+        <marker type="NODE_VARIABLE_DEF">sensitivity_alias unclassified = s0;</marker>
+    "#,
+    )
+}
+
+#[test]
+fn parse_bool_def() {
+    super::test_parser(
+        r#"
+        <marker type="NODE_VARIABLE_DEF">bool allow_write = true;</marker>
+    "#,
+    )
+}
+
+#[test]
+fn parse_tunable_def() {
+    super::test_parser(
+        r#"
+        <marker type="NODE_VARIABLE_DEF">tunable enable_debug = false;</marker>
+    "#,
+    )
+}
+
+#[test]
+fn parse_sid_def() {
+    super::test_parser(
+        r#"
+        <marker type="NODE_VARIABLE_DEF">sid kernel;</marker>
+    "#,
+    )
+}
+
+#[test]
+fn parse_type_alias_def() {
+    super::test_parser(
+        r#"
+        <marker type="NODE_TYPE_ALIAS_DEF">type_alias unconfined = unconfined_t;</marker>
+    "#,
+    )
+}
+
+#[test]
+fn parse_type_bounds_def() {
+    super::test_parser(
+        r#"
+        <marker type="NODE_TYPE_BOUNDS_DEF">type_bounds httpd_t httpd_child_t;</marker>
+    "#,
+    )
+}
+
+#[test]
+fn parse_permissive_def() {
+    super::test_parser(
+        r#"
+        <marker type="NODE_PERMISSIVE_DEF">permissive httpd_t;</marker>
+    "#,
+    )
+}
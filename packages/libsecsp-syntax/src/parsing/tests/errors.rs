@@ -0,0 +1,297 @@
+use proptest::prelude::*;
+use secsp_parser::syntax::SyntaxKind;
+use text_unit::{TextRange, TextUnit};
+
+use crate::SourceFile;
+
+fn range(start: u32, end: u32) -> TextRange {
+    TextRange::from_to(TextUnit::from(start), TextUnit::from(end))
+}
+
+#[test]
+fn valid_source_has_no_errors() {
+    let parse = SourceFile::parse("block a { type t; allow t t : file (read write); }");
+
+    assert!(parse.errors().is_empty(), "{:#?}", parse.errors());
+}
+
+#[test]
+fn missing_semicolon() {
+    let parse = SourceFile::parse("type t");
+    let errors = parse.errors();
+
+    assert_eq!(1, errors.len(), "{:#?}", errors);
+    assert_eq!(&[SyntaxKind::TOK_SEMICOLON], errors[0].expected());
+    assert_eq!(SyntaxKind::TOK_EOF, errors[0].found());
+    assert_eq!(range(6, 6), errors[0].range());
+}
+
+#[test]
+fn error_ranges_skip_trivia() {
+    let parse = SourceFile::parse("allow a b  // comment\n  file (read);");
+    let errors = parse.errors();
+
+    assert_eq!(1, errors.len(), "{:#?}", errors);
+    assert_eq!(&[SyntaxKind::TOK_COLON], errors[0].expected());
+    assert_eq!(SyntaxKind::TOK_NAME, errors[0].found());
+    assert_eq!(range(24, 28), errors[0].range());
+}
+
+#[test]
+fn expected_expression() {
+    let parse = SourceFile::parse("callstub(a, ;);");
+    let errors = parse.errors();
+
+    assert_eq!("expected expression", errors[0].message());
+    assert_eq!(SyntaxKind::TOK_SEMICOLON, errors[0].found());
+    assert_eq!(range(12, 13), errors[0].range());
+}
+
+#[test]
+fn expected_one_of() {
+    let parse = SourceFile::parse("type t;\nmy_ident my_other_ident;");
+    let errors = parse.errors();
+
+    assert_eq!(1, errors.len(), "{:#?}", errors);
+    assert_eq!("expected macro call or set modifier", errors[0].message());
+    assert_eq!(
+        &[
+            SyntaxKind::TOK_OPEN_PARENTHESIS,
+            SyntaxKind::TOK_PIPE_EQUALS,
+            SyntaxKind::TOK_HYPHEN_EQUALS,
+        ],
+        errors[0].expected()
+    );
+    assert_eq!(SyntaxKind::TOK_NAME, errors[0].found());
+    assert_eq!(range(17, 31), errors[0].range());
+}
+
+#[test]
+fn unterminated_block_comment() {
+    let parse = SourceFile::parse("type t; /* a /* b */");
+    let errors = parse.errors();
+
+    assert_eq!(1, errors.len(), "{:#?}", errors);
+    assert_eq!("unterminated block comment", errors[0].message());
+    assert_eq!(range(8, 20), errors[0].range());
+}
+
+#[test]
+fn unterminated_string() {
+    let parse = SourceFile::parse("import \"types;\ntype t;");
+    let errors = parse.errors();
+
+    // The semicolon is part of the string, so the import is missing one too.
+    assert_eq!(2, errors.len(), "{:#?}", errors);
+    assert_eq!("unterminated string", errors[0].message());
+    assert_eq!(SyntaxKind::TOK_UNTERMINATED_STRING, errors[0].found());
+    assert_eq!(range(7, 14), errors[0].range());
+}
+
+#[test]
+fn invalid_escape_sequence() {
+    let parse = SourceFile::parse(r#"file_context "/lib/.*\.so" u:r:lib_t;"#);
+    let errors = parse.errors();
+
+    assert_eq!(1, errors.len(), "{:#?}", errors);
+    assert_eq!("invalid escape sequence", errors[0].message());
+    assert_eq!(range(21, 23), errors[0].range());
+}
+
+#[test]
+fn keyword_without_statement() {
+    let parse = SourceFile::parse("type ;");
+    let errors = parse.errors();
+
+    assert_eq!(1, errors.len(), "{:#?}", errors);
+    assert_eq!("expected statement", errors[0].message());
+    assert_eq!(range(0, 4), errors[0].range());
+}
+
+#[test]
+fn invalid_macro_param_type() {
+    let parse = SourceFile::parse("macro m(allow a, t b) {}");
+    let errors = parse.errors();
+
+    assert_eq!(2, errors.len(), "{:#?}", errors);
+    assert_eq!("expected parameter type", errors[0].message());
+    assert_eq!(range(8, 13), errors[0].range());
+    assert_eq!("expected parameter type", errors[1].message());
+    assert_eq!(range(17, 18), errors[1].range());
+}
+
+#[test]
+fn param_types_are_identifiers_outside_macro_params() {
+    let parse =
+        SourceFile::parse("name(level, string); type name; allow name level : file (read);");
+
+    assert!(parse.errors().is_empty(), "{:#?}", parse.errors());
+}
+
+#[test]
+fn stray_closing_brace_keeps_text() {
+    let text = "type t; } type u;\n";
+    let parse = SourceFile::parse(text);
+
+    assert_eq!(text, parse.syntax_node().text().to_string());
+}
+
+#[test]
+fn recovery_within_block() {
+    let parse = SourceFile::parse("block a { type ; } block b {}");
+    let errors = parse.errors();
+
+    assert_eq!(1, errors.len(), "{:#?}", errors);
+    assert_eq!(range(10, 14), errors[0].range());
+}
+
+/// Fragments of source text that are each lexed as a single token, including keywords in places
+/// that they can't be used.
+const TOKENS: &[&str] = &[
+    "type",
+    "type_attribute",
+    "role",
+    "user",
+    "bool",
+    "block",
+    "abstract",
+    "extends",
+    "in",
+    "optional",
+    "macro",
+    "class",
+    "common",
+    "type_alias",
+    "type_bounds",
+    "permissive",
+    "allow",
+    "allow_xperm",
+    "ioctl",
+    "type_transition",
+    "role_transition",
+    "user_role",
+    "class_order",
+    "sid_context",
+    "import",
+    "level",
+    "name",
+    "constrain",
+    "dom",
+    "file_context",
+    "port_context",
+    "if",
+    "else",
+    "a",
+    ".b",
+    "\"s\"",
+    "\"\\s\"",
+    "/usr/bin(/.*)?",
+    "/",
+    "1",
+    "0x10",
+    "true",
+    "10.0.0.1",
+    "(",
+    ")",
+    "{",
+    "}",
+    ";",
+    ",",
+    ":",
+    ".",
+    "..",
+    "-",
+    "=",
+    "|=",
+    "-=",
+    "==",
+    "!=",
+    "!",
+    "~",
+    "&&",
+    "||",
+    "&",
+    "|",
+    "^",
+    "//",
+    "/*",
+    "*/",
+    "///",
+    "\"",
+    "?",
+    " ",
+    "\n",
+];
+
+proptest! {
+    #[test]
+    fn arbitrary_tokens_parse_losslessly(
+        tokens in prop::collection::vec(prop::sample::select(TOKENS), 0..64)
+    ) {
+        let text = tokens.join(" ");
+
+        prop_assert_eq!(&text, &SourceFile::parse(&text).syntax_node().text().to_string());
+    }
+}
+
+#[test]
+fn misplaced_keywords_are_reported() {
+    for text in &[
+        "extends a;",
+        "abstract;",
+        "macro;",
+        "ioctl 1;",
+        "dom a b;",
+        "else {}",
+    ] {
+        let parse = SourceFile::parse(text);
+
+        assert!(!parse.errors().is_empty(), "no errors for {:?}", text);
+        assert_eq!(*text, parse.syntax_node().text().to_string());
+    }
+}
+
+#[test]
+fn deeply_nested_input_is_skipped() {
+    let depth = 10_000;
+    let texts = vec![
+        format!("allow a b : c {}{};", "(".repeat(depth), ")".repeat(depth)),
+        format!("allow a b : c && {}d;", "!".repeat(depth)),
+        format!(
+            "constrain c {}a == b{};",
+            "!(".repeat(depth),
+            ")".repeat(depth)
+        ),
+        format!("{}{}", "block a { ".repeat(depth), "}".repeat(depth)),
+    ];
+
+    for text in texts {
+        let parse = SourceFile::parse(&text);
+
+        assert!(!parse.errors().is_empty());
+        assert!(parse
+            .errors()
+            .iter()
+            .any(|error| error.message().ends_with("nested too deeply")));
+        assert_eq!(text, parse.syntax_node().text().to_string());
+    }
+}
+
+#[test]
+fn long_operator_chains_are_not_nested_too_deeply() {
+    let terms = 200;
+    let texts = vec![
+        format!("allow a b : c (d{});", " | d".repeat(terms)),
+        format!("if a{} {{}}", " && a".repeat(terms)),
+        format!(
+            "constrain c (read) t1 == t2{};",
+            " || r1 == r2".repeat(terms)
+        ),
+    ];
+
+    for text in texts {
+        let parse = SourceFile::parse(&text);
+
+        assert!(parse.errors().is_empty(), "{:#?}", parse.errors());
+    }
+}
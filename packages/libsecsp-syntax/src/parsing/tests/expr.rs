@@ -25,6 +25,24 @@ fn parse_paren_expr() {
     )
 }
 
+#[test]
+fn parse_list_expr() {
+    super::test_parser(
+        r#"
+        callstub(<marker type="NODE_LIST_EXPR">(a, b)</marker>);
+    "#,
+    )
+}
+
+#[test]
+fn parse_whitespace_separated_list_expr() {
+    super::test_parser(
+        r#"
+        callstub(<marker type="NODE_LIST_EXPR">(read write)</marker>);
+    "#,
+    )
+}
+
 #[test]
 fn parse_context_expr() {
     super::test_parser(
@@ -71,3 +89,12 @@ fn parse_level_range_expr() {
         "#,
     )
 }
+
+#[test]
+fn parse_cast_expr() {
+    super::test_parser(
+        r#"
+        my_type |= <marker type="NODE_CAST_EXPR">(type) (typea typeb typec)</marker>;
+    "#,
+    )
+}
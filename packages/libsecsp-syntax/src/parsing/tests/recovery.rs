@@ -0,0 +1,168 @@
+#[test]
+fn recover_from_missing_semicolon() {
+    super::test_recovery(
+        r#"
+        allow a b : file (read)
+        <marker type="NODE_TE_RULE">allow c d : file (write);</marker>
+    "#,
+        &["expected Semicolon"],
+    );
+}
+
+#[test]
+fn recover_from_missing_semicolon_before_definition() {
+    super::test_recovery(
+        r#"
+        type_transition a b : process c
+        <marker type="NODE_VARIABLE_DEF">type d;</marker>
+    "#,
+        &["expected Semicolon"],
+    );
+}
+
+#[test]
+fn recover_from_missing_parentheses() {
+    super::test_recovery(
+        r#"
+        allow a b : file read write;
+        <marker type="NODE_TE_RULE">allow c d : file (write);</marker>
+    "#,
+        &["expected Semicolon"],
+    );
+}
+
+#[test]
+fn recover_from_unexpected_tokens_before_statement() {
+    super::test_recovery(
+        r#"
+        <marker type="NODE_TE_RULE">allow a b : file (read) <marker type="NODE_PARSE_ERROR">read write</marker></marker>
+        allow c d : file (write);
+    "#,
+        &["expected Semicolon"],
+    );
+}
+
+#[test]
+fn recover_from_unclosed_list() {
+    super::test_recovery(
+        r#"
+        allow a b : file (read write
+        <marker type="NODE_TE_RULE">allow c d : file (write);</marker>
+    "#,
+        &["expected CloseParenthesis"],
+    );
+}
+
+#[test]
+fn recover_from_unclosed_macro_call() {
+    super::test_recovery(
+        r#"
+        macro_call(a, b
+        <marker type="NODE_VARIABLE_DEF">type t;</marker>
+    "#,
+        &["expected CloseParenthesis"],
+    );
+}
+
+#[test]
+fn recover_from_missing_macro_argument() {
+    super::test_recovery(
+        r#"
+        <marker type="NODE_MACRO_CALL">macro_call(a, , b);</marker>
+        type t;
+    "#,
+        &["expected expression"],
+    );
+}
+
+#[test]
+fn recover_from_missing_access_vector() {
+    super::test_recovery(
+        r#"
+        allow a b : ;
+        <marker type="NODE_TE_RULE">allow c d : file (write);</marker>
+    "#,
+        &["expected expression"],
+    );
+}
+
+#[test]
+fn recover_from_missing_target() {
+    super::test_recovery(
+        r#"
+        allow a
+        <marker type="NODE_TE_RULE">allow c d : file (write);</marker>
+    "#,
+        &["expected expression"],
+    );
+}
+
+#[test]
+fn recover_from_missing_result() {
+    super::test_recovery(
+        r#"
+        role_transition r t : process
+        <marker type="NODE_VARIABLE_DEF">role s;</marker>
+    "#,
+        &["expected expression"],
+    );
+}
+
+#[test]
+fn recover_from_missing_condition_operand() {
+    super::test_recovery(
+        r#"
+        <marker type="NODE_CONDITIONAL_STMT">if a && <marker type="NODE_BLOCK">{
+            <marker type="NODE_VARIABLE_DEF">type t;</marker>
+        }</marker></marker>
+    "#,
+        &["expected expression"],
+    );
+}
+
+#[test]
+fn recover_from_unknown_statement() {
+    super::test_recovery(
+        r#"
+        blok a { type t; }
+        <marker type="NODE_VARIABLE_DEF">type u;</marker>
+    "#,
+        &["expected macro call or set modifier"],
+    );
+}
+
+#[test]
+fn recover_within_block() {
+    super::test_recovery(
+        r#"
+        <marker type="NODE_CONTAINER_DEF">block a {
+            allow a b : file (read)
+            <marker type="NODE_VARIABLE_DEF">type t;</marker>
+        }</marker>
+        block b {}
+    "#,
+        &["expected Semicolon"],
+    );
+}
+
+#[test]
+fn recover_from_stray_closing_brace() {
+    super::test_recovery(
+        r#"
+        type t; }
+        <marker type="NODE_VARIABLE_DEF">type u;</marker>
+    "#,
+        &["unexpected closing brace"],
+    );
+}
+
+#[test]
+fn recover_from_missing_statement_keyword() {
+    super::test_recovery(
+        r#"
+        a b : file (read);
+        <marker type="NODE_TE_RULE">allow c d : file (write);</marker>
+    "#,
+        &["expected macro call or set modifier"],
+    );
+}
@@ -0,0 +1,227 @@
+use proptest::prelude::*;
+use rowan::WalkEvent;
+use text_unit::{TextRange, TextUnit};
+
+use secsp_parser::syntax::{SyntaxElement, SyntaxNode};
+
+use crate::parsing::reparsing::incremental_reparse;
+use crate::parsing::Parse;
+use crate::{SourceFile, TextEdit};
+
+const POLICY: &str = r#"/// A documented block.
+block a {
+    type t;
+    // A comment.
+    macro m(type p) {
+        allow p t : file (read write);
+    }
+
+    /* A block
+       comment. */
+    block inner {
+        file_context "/tmp" dir u:r:t;
+    }
+}
+
+if b {
+    type_attribute x;
+} else {
+    type y;
+}
+"#;
+
+/// Fragments of text that are likely to change the structure of the tree when inserted.
+const FRAGMENTS: &[&str] = &[
+    "",
+    " ",
+    "\n\n",
+    "x",
+    ";",
+    "{",
+    "}",
+    "/*",
+    "*/",
+    "//",
+    "///",
+    "\"",
+    "\"\n",
+    "\\",
+    "/",
+    "type z;",
+    "block b {}",
+    "macro n() { }",
+    "allow",
+];
+
+fn edit(start: u32, end: u32, text: &str) -> TextEdit {
+    TextEdit::replace(
+        TextRange::from_to(TextUnit::from(start), TextUnit::from(end)),
+        text.to_string(),
+    )
+}
+
+fn offset_of(text: &str, pattern: &str) -> u32 {
+    text.find(pattern).expect("pattern not found") as u32
+}
+
+fn tree_to_string(node: &SyntaxNode) -> String {
+    let mut out = String::new();
+
+    for event in node.preorder_with_tokens() {
+        if let WalkEvent::Enter(element) = event {
+            match element {
+                SyntaxElement::Node(node) => out += &format!("{:?}\n", node),
+                SyntaxElement::Token(token) => out += &format!("{:?}\n", token),
+            }
+        }
+    }
+
+    out
+}
+
+fn assert_same_parse(expected: &Parse<SourceFile>, actual: &Parse<SourceFile>) {
+    assert_eq!(
+        tree_to_string(&expected.syntax_node()),
+        tree_to_string(&actual.syntax_node())
+    );
+    assert_eq!(expected.errors(), actual.errors());
+}
+
+/// Check that reparsing [text] after [edit] gives the same result as a full parse of the edited
+/// text, returning whether the reparse was incremental.
+fn check_reparse(text: &str, edit: &TextEdit) -> bool {
+    let parse = SourceFile::parse(text);
+    let expected = SourceFile::parse(edit.apply(text));
+
+    assert_same_parse(&expected, &parse.reparse(edit));
+    incremental_reparse(&parse, edit).is_some()
+}
+
+#[test]
+fn edit_within_whitespace() {
+    let offset = offset_of(POLICY, "    type t;");
+
+    assert!(check_reparse(POLICY, &edit(offset, offset + 2, "")));
+}
+
+#[test]
+fn edit_within_comment() {
+    let offset = offset_of(POLICY, "A comment");
+
+    assert!(check_reparse(POLICY, &edit(offset, offset + 1, "Another")));
+}
+
+#[test]
+fn edit_within_block() {
+    let offset = offset_of(POLICY, "read write");
+
+    assert!(check_reparse(POLICY, &edit(offset, offset + 4, "getattr")));
+}
+
+#[test]
+fn edit_adding_errors_within_block() {
+    let offset = offset_of(POLICY, "type y;");
+    let text = "type ;";
+
+    assert!(check_reparse(POLICY, &edit(offset, offset + 7, text)));
+    assert!(!SourceFile::parse(POLICY)
+        .reparse(&edit(offset, offset + 7, text))
+        .errors()
+        .is_empty());
+}
+
+#[test]
+fn edit_adding_invalid_escape_within_string() {
+    let offset = offset_of(POLICY, "/tmp");
+    let text = "\\.";
+
+    assert!(check_reparse(POLICY, &edit(offset + 1, offset + 1, text)));
+    assert_eq!(
+        1,
+        SourceFile::parse(POLICY)
+            .reparse(&edit(offset + 1, offset + 1, text))
+            .errors()
+            .len()
+    );
+}
+
+#[test]
+fn edit_unbalancing_block() {
+    let offset = offset_of(POLICY, "type t;");
+
+    assert!(!check_reparse(POLICY, &edit(offset, offset, "}")));
+}
+
+#[test]
+fn edit_crossing_blocks() {
+    let start = offset_of(POLICY, "type_attribute");
+    let end = offset_of(POLICY, "type y");
+
+    assert!(!check_reparse(POLICY, &edit(start, end, "")));
+}
+
+#[test]
+fn edit_joining_unterminated_string_to_next_line() {
+    let text = "\"\n // { ..";
+
+    assert!(!check_reparse(text, &edit(1, 2, "")));
+}
+
+#[test]
+fn edit_separating_doc_comment() {
+    let offset = offset_of(POLICY, "\nblock a");
+
+    assert!(!check_reparse(POLICY, &edit(offset, offset, "\n")));
+}
+
+#[test]
+fn edit_within_deeply_nested_block() {
+    // Blocks nested around the limit of how deeply the parser nests nodes.
+    for depth in 56..72 {
+        let text = format!("{}type t;{}", "block a { ".repeat(depth), "}".repeat(depth));
+        let offset = offset_of(&text, "type t;");
+
+        check_reparse(
+            &text,
+            &edit(offset, offset + 7, "block b { allow a b : c ((d)); }"),
+        );
+    }
+}
+
+fn edits() -> impl Strategy<Value = Vec<(usize, usize, usize)>> {
+    prop::collection::vec((any::<usize>(), 0usize..16, 0..FRAGMENTS.len()), 1..5)
+}
+
+proptest! {
+    #[test]
+    fn incremental_reparse_matches_full_parse(edits in edits()) {
+        let mut text = POLICY.to_string();
+        let mut parse = SourceFile::parse(&text);
+
+        for (start, len, fragment) in edits {
+            let start = start % (text.len() + 1);
+            let end = (start + len).min(text.len());
+            let edit = edit(start as u32, end as u32, FRAGMENTS[fragment]);
+
+            text = edit.apply(&text);
+            parse = parse.reparse(&edit);
+
+            assert_same_parse(&SourceFile::parse(&text), &parse);
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn full_parse_is_lossless(edits in edits()) {
+        let mut text = POLICY.to_string();
+
+        for (start, len, fragment) in edits {
+            let start = start % (text.len() + 1);
+            let end = (start + len).min(text.len());
+            text = edit(start as u32, end as u32, FRAGMENTS[fragment]).apply(&text);
+        }
+
+        assert_eq!(text, SourceFile::parse(&text).syntax_node().text().to_string());
+    }
+}
@@ -7,6 +7,42 @@ fn parse_macro_call() {
     );
 }
 
+#[test]
+fn parse_macro_call_with_named_arguments() {
+    super::test_parser(
+        r#"
+        macro_name<marker type="NODE_MACRO_ARGUMENT_LIST">(a, <marker type="NODE_MACRO_ARGUMENT_LIST_ITEM">write = true</marker>)</marker>;
+    "#,
+    )
+}
+
+#[test]
+fn parse_macro_call_with_path_pattern_arguments() {
+    super::test_parser(
+        r#"
+        m<marker type="NODE_MACRO_ARGUMENT_LIST">(/tmp, /usr/lib(64)?/.*)</marker>;
+    "#,
+    )
+}
+
+#[test]
+fn parse_set_modifier() {
+    super::test_parser(
+        r#"
+        <marker type="NODE_SET_MODIFIER_STMT">my_attr |= a | b;</marker>
+    "#,
+    );
+}
+
+#[test]
+fn parse_set_modifier_removal() {
+    super::test_parser(
+        r#"
+        <marker type="NODE_SET_MODIFIER_STMT">.global.attr -= (a b);</marker>
+    "#,
+    );
+}
+
 #[test]
 fn parse_conditional() {
     super::test_parser(
@@ -47,3 +83,300 @@ fn parse_allow_rule() {
         "#,
     )
 }
+
+#[test]
+fn parse_class_order() {
+    super::test_parser(
+        r#"
+        <marker type="NODE_ORDER_STMT">class_order (file, dir);</marker>
+    "#,
+    )
+}
+
+#[test]
+fn parse_type_transition_rule() {
+    super::test_parser(
+        r#"
+        <marker type="NODE_TYPE_TRANSITION_RULE">type_transition a b : process c;</marker>
+        "#,
+    )
+}
+
+#[test]
+fn parse_named_type_transition_rule() {
+    super::test_parser(
+        r#"
+        <marker type="NODE_TYPE_TRANSITION_RULE">type_transition a b : file "name" c;</marker>
+        "#,
+    )
+}
+
+#[test]
+fn parse_type_change_rule() {
+    super::test_parser(
+        r#"
+        <marker type="NODE_TYPE_CHANGE_RULE">type_change a b : file c;</marker>
+        "#,
+    )
+}
+
+#[test]
+fn parse_type_member_rule() {
+    super::test_parser(
+        r#"
+        <marker type="NODE_TYPE_MEMBER_RULE">type_member a b : dir c;</marker>
+        "#,
+    )
+}
+
+#[test]
+fn parse_role_type_rule() {
+    super::test_parser(
+        r#"
+        <marker type="NODE_ROLE_TYPE_RULE">role_type r (a b);</marker>
+        "#,
+    )
+}
+
+#[test]
+fn parse_role_allow_rule() {
+    super::test_parser(
+        r#"
+        <marker type="NODE_ROLE_ALLOW_RULE">role_allow a b;</marker>
+        "#,
+    )
+}
+
+#[test]
+fn parse_role_transition_rule() {
+    super::test_parser(
+        r#"
+        <marker type="NODE_ROLE_TRANSITION_RULE">role_transition a b : process c;</marker>
+        "#,
+    )
+}
+
+#[test]
+fn parse_user_role_stmt() {
+    super::test_parser(
+        r#"
+        <marker type="NODE_USER_ROLE_STMT">user_role u (a b);</marker>
+        "#,
+    )
+}
+
+#[test]
+fn parse_user_level_stmt() {
+    super::test_parser(
+        r#"
+        user_level u <marker type="NODE_LEVEL_EXPR">s0:c0</marker>;
+        "#,
+    )
+}
+
+#[test]
+fn parse_user_range_stmt() {
+    super::test_parser(
+        r#"
+        <marker type="NODE_USER_RANGE_STMT">user_range u s0-s1:c0..c5;</marker>
+        "#,
+    )
+}
+
+#[test]
+fn parse_sensitivity_order() {
+    super::test_parser(
+        r#"
+        <marker type="NODE_ORDER_STMT">sensitivity_order (s0, s1);</marker>
+        "#,
+    )
+}
+
+#[test]
+fn parse_sensitivity_category_stmt() {
+    super::test_parser(
+        r#"
+        <marker type="NODE_SENSITIVITY_CATEGORY_STMT">sensitivity_category s0 c0..c255;</marker>
+        "#,
+    )
+}
+
+#[test]
+fn parse_constrain_stmt() {
+    super::test_parser(
+        r#"
+        <marker type="NODE_CONSTRAINT_STMT">constrain file (read write) u1 == u2 && r1 == r2;</marker>
+        "#,
+    )
+}
+
+#[test]
+fn parse_constraint_not_expr() {
+    super::test_parser(
+        r#"
+        validate_trans file <marker type="NODE_CONSTRAINT_NOT_EXPR">!(t1 == t2)</marker>;
+        "#,
+    )
+}
+
+#[test]
+fn parse_constraint_name_list() {
+    super::test_parser(
+        r#"
+        mls_constrain file (read) <marker type="NODE_CONSTRAINT_COMPARISON_EXPR">t1 == (a b)</marker>;
+        "#,
+    )
+}
+
+#[test]
+fn parse_constraint_on_class_permission() {
+    super::test_parser(
+        r#"
+        mls_constrain cp <marker type="NODE_CONSTRAINT_PAREN_EXPR">(l1 domby h2)</marker>;
+        "#,
+    )
+}
+
+#[test]
+fn parse_constraint_precedence() {
+    super::test_parser(
+        r#"
+        constrain cp u1 == u2 || <marker type="NODE_CONSTRAINT_BINARY_EXPR">r1 dom r2 && t1 != t2</marker>;
+        "#,
+    )
+}
+
+#[test]
+fn parse_file_context_stmt() {
+    super::test_parser(
+        r#"
+        <marker type="NODE_FILE_CONTEXT_STMT">file_context "/usr/bin(/.*)?" file system_u:object_r:bin_t;</marker>
+        "#,
+    )
+}
+
+#[test]
+fn parse_file_context_with_path_pattern() {
+    super::test_parser(
+        r#"
+        <marker type="NODE_FILE_CONTEXT_STMT">file_context /usr/lib/.*\.so u:r:lib_t:s0;</marker>
+        "#,
+    )
+}
+
+#[test]
+fn parse_file_context_without_file_type() {
+    super::test_parser(
+        r#"
+        file_context "/tmp" <marker type="NODE_CONTEXT_EXPR">u:r:tmp_t:s0</marker>;
+        "#,
+    )
+}
+
+#[test]
+fn parse_fs_use_stmt() {
+    super::test_parser(
+        r#"
+        <marker type="NODE_FS_USE_STMT">fs_use_xattr ext4 system_u:object_r:fs_t;</marker>
+        "#,
+    )
+}
+
+#[test]
+fn parse_genfscon_stmt() {
+    super::test_parser(
+        r#"
+        <marker type="NODE_GENFSCON_STMT">genfscon proc "/" system_u:object_r:proc_t;</marker>
+        "#,
+    )
+}
+
+#[test]
+fn parse_port_context_stmt() {
+    super::test_parser(
+        r#"
+        <marker type="NODE_PORT_CONTEXT_STMT">port_context tcp 8000-8080 system_u:object_r:port_t;</marker>
+        "#,
+    )
+}
+
+#[test]
+fn parse_netif_context_stmt() {
+    super::test_parser(
+        r#"
+        <marker type="NODE_NETIF_CONTEXT_STMT">netif_context eth0 u:r:netif_t u:r:packet_t;</marker>
+        "#,
+    )
+}
+
+#[test]
+fn parse_node_context_stmt() {
+    super::test_parser(
+        r#"
+        <marker type="NODE_NODE_CONTEXT_STMT">node_context 192.168.0.0 255.255.255.0 u:r:node_t;</marker>
+        "#,
+    )
+}
+
+#[test]
+fn parse_ibpkey_context_stmt() {
+    super::test_parser(
+        r#"
+        <marker type="NODE_IBPKEY_CONTEXT_STMT">ibpkey_context fe80:: 0x8000-0xffff u:r:pkey_t;</marker>
+        "#,
+    )
+}
+
+#[test]
+fn parse_ibendport_context_stmt() {
+    super::test_parser(
+        r#"
+        <marker type="NODE_IBENDPORT_CONTEXT_STMT">ibendport_context mlx4_0 1 u:r:endport_t;</marker>
+        "#,
+    )
+}
+
+#[test]
+fn parse_sid_order() {
+    super::test_parser(
+        r#"
+        <marker type="NODE_ORDER_STMT">sid_order (kernel, security);</marker>
+        "#,
+    )
+}
+
+#[test]
+fn parse_sid_context_stmt() {
+    super::test_parser(
+        r#"
+        <marker type="NODE_SID_CONTEXT_STMT">sid_context kernel system_u:system_r:kernel_t:s0;</marker>
+        "#,
+    )
+}
+
+#[test]
+fn parse_xperm_rule() {
+    super::test_parser(
+        r#"
+        <marker type="NODE_XPERM_RULE">allow_xperm src tgt : tcp_socket ioctl (0x8900, 0x8910-0x891f);</marker>
+        "#,
+    )
+}
+
+#[test]
+fn parse_xperm_range() {
+    super::test_parser(
+        r#"
+        dont_audit_xperm src tgt : tcp_socket ioctl <marker type="NODE_XPERM_RANGE">0x8900-0x89ff</marker>;
+        "#,
+    )
+}
+
+#[test]
+fn parse_import_stmt() {
+    super::test_parser(
+        r#"
+        <marker type="NODE_IMPORT_STMT">import "types/base.csp";</marker>
+        "#,
+    )
+}
@@ -39,7 +39,11 @@ impl<'t> TextTreeSink<'t> {
 
     pub fn finish(mut self) -> (GreenNode, Vec<ParseError>) {
         match mem::replace(&mut self.state, State::Normal) {
-            State::PendingFinish => self.builder.finish_node(),
+            State::PendingFinish => {
+                // Trivia after the last token belongs to the root node.
+                self.eat_trivias();
+                self.builder.finish_node()
+            }
             State::Normal | State::PendingStart => (),
         };
         (self.builder.finish(), self.errors)
@@ -72,8 +76,23 @@ impl<'t> TextTreeSink<'t> {
 }
 
 impl<'t> TreeSink for TextTreeSink<'t> {
-    fn error(&mut self, _error: ParseError) {
-        unimplemented!()
+    fn error(&mut self, error: ParseError) {
+        // Errors are reported against the next significant token, which is the token the
+        // parser was looking at when it failed.
+        let mut offset = self.text_pos;
+        let mut len = TextUnit::from(0);
+
+        for token in &self.tokens[self.token_pos..] {
+            if token.is_trivia() {
+                offset += token.len();
+            } else {
+                len = token.len();
+                break;
+            }
+        }
+
+        let range = TextRange::offset_len(offset, len);
+        self.errors.push(error.with_range(range));
     }
 
     fn start_node(&mut self, kind: SyntaxKind) {
@@ -88,6 +107,12 @@ impl<'t> TreeSink for TextTreeSink<'t> {
             State::Normal => (),
         }
 
+        // Leading trivia is left for the next token or node, unless it documents this node.
+        if !is_documented(kind) {
+            self.builder.start_node(kind.into());
+            return;
+        }
+
         let n_trivias = self.tokens[self.token_pos..]
             .iter()
             .take_while(|it| it.is_trivia())
@@ -96,16 +121,12 @@ impl<'t> TreeSink for TextTreeSink<'t> {
         let mut trivia_end =
             self.text_pos + leading_trivias.iter().map(|it| it.len()).sum::<TextUnit>();
 
-        let n_attached_trivias = leading_trivias
-            .iter()
-            .rev()
-            .map(|it| {
-                let next_end = trivia_end - it.len();
-                let range = TextRange::from_to(next_end, trivia_end);
-                trivia_end = next_end;
-                (it.kind(), &self.text[range])
-            })
-            .count();
+        let n_attached_trivias = n_attached_doc_comments(leading_trivias.iter().rev().map(|it| {
+            let next_end = trivia_end - it.len();
+            let range = TextRange::from_to(next_end, trivia_end);
+            trivia_end = next_end;
+            (it.kind(), &self.text[range])
+        }));
         self.eat_n_trivias(n_trivias - n_attached_trivias);
         self.builder.start_node(kind.into());
         self.eat_n_trivias(n_attached_trivias);
@@ -132,3 +153,28 @@ impl<'t> TreeSink for TextTreeSink<'t> {
         self.do_token(kind, len);
     }
 }
+
+/// Check if nodes of the given kind can have documentation comments attached.
+fn is_documented(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::NODE_CONTAINER_DEF | SyntaxKind::NODE_MACRO_DEF | SyntaxKind::NODE_VARIABLE_DEF
+    )
+}
+
+/// Count the trivia that belong to a documented node, given the trivia before it in reverse
+/// order. Only the run of doc comments directly before the node is attached, and a blank line
+/// ends the run.
+fn n_attached_doc_comments<'a>(trivias: impl Iterator<Item = (SyntaxKind, &'a str)>) -> usize {
+    let mut n_attached = 0;
+
+    for (count, (kind, text)) in trivias.enumerate() {
+        match kind {
+            SyntaxKind::TOK_WHITESPACE if text.matches('\n').count() <= 1 => {}
+            SyntaxKind::TOK_DOC_COMMENT => n_attached = count + 1,
+            _ => break,
+        }
+    }
+
+    n_attached
+}
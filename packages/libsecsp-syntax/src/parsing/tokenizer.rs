@@ -4,6 +4,7 @@
 //! The output of the tokenizer is lightweight, and only contains information about the
 //! type of a [Token] and where it occurred in the source.
 
+use std::net::{Ipv4Addr, Ipv6Addr};
 use std::ops::Range;
 
 use itertools::Itertools;
@@ -52,12 +53,20 @@ impl<'a> Iterator for Tokenizer<'a> {
 /// Runs the tokenizer on an input string and collects all of the output tokens
 /// into a list, continuing past lex errors.
 pub fn tokenize<S: AsRef<str>>(str: S) -> Vec<Token> {
-    let tokenizer = Tokenizer::new(str.as_ref());
+    let text = str.as_ref();
+    let tokenizer = Tokenizer::new(text);
     let mut tokens: Vec<Token> = vec![];
     let mut iter = tokenizer.peekable();
 
     while let Some((token, range)) = iter.next() {
         match token {
+            // `////` and longer are separators rather than documentation.
+            TokenKind::LineComment
+                if text[range.clone()].starts_with("///")
+                    && !text[range.clone()].starts_with("////") =>
+            {
+                tokens.push(Token::new(TokenKind::DocComment, range))
+            }
             TokenKind::Illegal | TokenKind::Whitespace | TokenKind::LineComment => {
                 let range = iter
                     .peeking_take_while(|(ty, _)| *ty == token)
@@ -71,7 +80,55 @@ pub fn tokenize<S: AsRef<str>>(str: S) -> Vec<Token> {
         }
     }
 
-    tokens
+    merge_addresses(text, tokens)
+}
+
+/// Combine runs of adjacent names, integers, colons and dots that spell out an IP address into
+/// a single address token. The lexer can't do this itself, since the parts of an IPv6 address
+/// are indistinguishable from the parts of a context until the whole run is seen.
+fn merge_addresses(text: &str, tokens: Vec<Token>) -> Vec<Token> {
+    fn is_address_part(token: &Token) -> bool {
+        matches!(
+            (*token).into(),
+            TokenKind::Name | TokenKind::Integer | TokenKind::Colon | TokenKind::Dot
+        )
+    }
+
+    let mut merged = Vec::with_capacity(tokens.len());
+    let mut idx = 0;
+
+    while idx < tokens.len() {
+        let mut end = idx + 1;
+
+        if is_address_part(&tokens[idx]) {
+            while end < tokens.len()
+                && is_address_part(&tokens[end])
+                && tokens[end].range().start == tokens[end - 1].range().end
+            {
+                end += 1;
+            }
+        }
+
+        let range = tokens[idx].range().start..tokens[end - 1].range().end;
+        let address_kind = if end - idx == 1 {
+            None
+        } else if text[range.clone()].parse::<Ipv4Addr>().is_ok() {
+            Some(TokenKind::Ipv4Address)
+        } else if text[range.clone()].parse::<Ipv6Addr>().is_ok() {
+            Some(TokenKind::Ipv6Address)
+        } else {
+            None
+        };
+
+        match address_kind {
+            Some(kind) => merged.push(Token::new(kind, range)),
+            None => merged.extend_from_slice(&tokens[idx..end]),
+        }
+
+        idx = end;
+    }
+
+    merged
 }
 
 #[test]
@@ -93,3 +150,149 @@ fn preserves_whitespace() {
         types
     );
 }
+
+#[test]
+fn merges_ip_addresses() {
+    let types: Vec<TokenKind> = tokenize("10.0.0.1 fe80::1 ::ffff:0.0.0.1 u:r:t")
+        .into_iter()
+        .map(|t| t.into())
+        .collect();
+
+    assert_eq!(
+        vec![
+            TokenKind::Ipv4Address,
+            TokenKind::Whitespace,
+            TokenKind::Ipv6Address,
+            TokenKind::Whitespace,
+            TokenKind::Ipv6Address,
+            TokenKind::Whitespace,
+            TokenKind::Name,
+            TokenKind::Colon,
+            TokenKind::Name,
+            TokenKind::Colon,
+            TokenKind::Name,
+            TokenKind::Eof,
+        ],
+        types
+    );
+}
+
+#[test]
+fn nested_block_comments() {
+    let tokens = tokenize("/* a /* b */ c */ d");
+    let types: Vec<TokenKind> = tokens.iter().map(|&t| t.into()).collect();
+
+    assert_eq!(
+        vec![
+            TokenKind::BlockComment,
+            TokenKind::Whitespace,
+            TokenKind::Name,
+            TokenKind::Eof,
+        ],
+        types
+    );
+    assert_eq!(0..17, tokens[0].range());
+}
+
+#[test]
+fn unterminated_block_comment_runs_to_end() {
+    let tokens = tokenize("type t; /* a /* b */");
+
+    assert_eq!(8..20, tokens[5].range());
+    assert_eq!(TokenKind::BlockComment, tokens[5].into());
+}
+
+#[test]
+fn doc_comments() {
+    let types: Vec<TokenKind> = tokenize("/// a\n// b\n//// c")
+        .into_iter()
+        .map(|t| t.into())
+        .collect();
+
+    assert_eq!(
+        vec![
+            TokenKind::DocComment,
+            TokenKind::Whitespace,
+            TokenKind::LineComment,
+            TokenKind::Whitespace,
+            TokenKind::LineComment,
+            TokenKind::Eof,
+        ],
+        types
+    );
+}
+
+#[test]
+fn strings_end_at_closing_quote() {
+    let tokens = tokenize(r#""a \" b \\" c"#);
+
+    assert_eq!(TokenKind::String, tokens[0].into());
+    assert_eq!(0..11, tokens[0].range());
+}
+
+#[test]
+fn unterminated_string_runs_to_end_of_line() {
+    let tokens = tokenize("import \"a;\ntype t;");
+    let types: Vec<TokenKind> = tokens.iter().map(|&t| t.into()).collect();
+
+    assert_eq!(
+        vec![
+            TokenKind::Name,
+            TokenKind::Whitespace,
+            TokenKind::UnterminatedString,
+            TokenKind::Whitespace,
+            TokenKind::Name,
+            TokenKind::Whitespace,
+            TokenKind::Name,
+            TokenKind::Semicolon,
+            TokenKind::Eof,
+        ],
+        types
+    );
+    assert_eq!(7..10, tokens[2].range());
+}
+
+#[test]
+fn path_patterns() {
+    let tokens = tokenize("/usr/lib(64)?/.*\\.so; / // c\n/* d */");
+    let types: Vec<TokenKind> = tokens.iter().map(|&t| t.into()).collect();
+
+    assert_eq!(
+        vec![
+            TokenKind::PathPattern,
+            TokenKind::Semicolon,
+            TokenKind::Whitespace,
+            TokenKind::PathPattern,
+            TokenKind::Whitespace,
+            TokenKind::LineComment,
+            TokenKind::Whitespace,
+            TokenKind::BlockComment,
+            TokenKind::Eof,
+        ],
+        types
+    );
+    assert_eq!(0..20, tokens[0].range());
+}
+
+#[test]
+fn path_patterns_end_before_unbalanced_parentheses() {
+    let tokens = tokenize("m(/tmp, /(a|b)/c);");
+    let types: Vec<TokenKind> = tokens.iter().map(|&t| t.into()).collect();
+
+    assert_eq!(
+        vec![
+            TokenKind::Name,
+            TokenKind::OpenParenthesis,
+            TokenKind::PathPattern,
+            TokenKind::Comma,
+            TokenKind::Whitespace,
+            TokenKind::PathPattern,
+            TokenKind::CloseParenthesis,
+            TokenKind::Semicolon,
+            TokenKind::Eof,
+        ],
+        types
+    );
+    assert_eq!(2..6, tokens[2].range());
+    assert_eq!(8..16, tokens[5].range());
+}
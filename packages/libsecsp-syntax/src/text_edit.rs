@@ -0,0 +1,60 @@
+use text_unit::{TextRange, TextUnit};
+
+/// A change to the text of a source file, replacing a range of the text with new text.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TextEdit {
+    range: TextRange,
+    text: String,
+}
+
+impl TextEdit {
+    pub fn replace(range: TextRange, text: String) -> Self {
+        TextEdit { range, text }
+    }
+
+    pub fn insert(offset: TextUnit, text: String) -> Self {
+        TextEdit::replace(TextRange::offset_len(offset, 0.into()), text)
+    }
+
+    pub fn delete(range: TextRange) -> Self {
+        TextEdit::replace(range, String::new())
+    }
+
+    /// Get the range of the original text that is replaced.
+    pub fn range(&self) -> TextRange {
+        self.range
+    }
+
+    /// Get the text that replaces the edited range.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Apply this edit to the whole of a source file's text.
+    pub fn apply(&self, text: &str) -> String {
+        self.apply_at(0.into(), text)
+    }
+
+    /// Apply this edit to a fragment of a source file's text that starts at [offset]. The edited
+    /// range must be within the fragment.
+    pub(crate) fn apply_at(&self, offset: TextUnit, text: &str) -> String {
+        let start = (self.range.start() - offset).to_usize();
+        let end = (self.range.end() - offset).to_usize();
+
+        let mut result = String::with_capacity(text.len() - (end - start) + self.text.len());
+        result.push_str(&text[..start]);
+        result.push_str(&self.text);
+        result.push_str(&text[end..]);
+        result
+    }
+
+    /// Find where an offset in the original text ends up after this edit. The offset must not
+    /// be within the edited range.
+    pub(crate) fn shift(&self, offset: TextUnit) -> TextUnit {
+        if offset < self.range.end() {
+            offset
+        } else {
+            offset - self.range.len() + TextUnit::of_str(&self.text)
+        }
+    }
+}
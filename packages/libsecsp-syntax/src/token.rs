@@ -27,10 +27,13 @@ impl Token {
     }
 
     pub fn is_trivia(&self) -> bool {
-        match self.0 {
-            TokenKind::LineComment | TokenKind::Whitespace => true,
-            _ => false,
-        }
+        matches!(
+            self.0,
+            TokenKind::LineComment
+                | TokenKind::BlockComment
+                | TokenKind::DocComment
+                | TokenKind::Whitespace
+        )
     }
 }
 
@@ -0,0 +1,122 @@
+//! Generation of the typed AST wrappers of `secsp_syntax`, their child accessors, and the
+//! enums over them.
+
+use std::fmt::Write;
+
+use crate::grammar::{Grammar, Node};
+use crate::{doc_comment, header, Result};
+
+pub fn generate(grammar: &Grammar) -> Result<String> {
+    let mut out = String::new();
+
+    header(&mut out);
+    out.push_str(
+        "\nuse secsp_parser::syntax::SyntaxNode;\n\
+         use secsp_syntax_derive::{AstEnum, AstType};\n\
+         \n\
+         use crate::ast::types::*;\n\
+         use crate::ast::{AstChildren, AstNode};\n",
+    );
+
+    for node in &grammar.nodes {
+        if let Some(name) = &node.ast {
+            ast_type(node, name, &mut out);
+        }
+    }
+
+    for ast_enum in &grammar.enums {
+        out.push('\n');
+        doc_comment(&mut out, 0, ast_enum.doc.as_deref());
+        writeln!(
+            out,
+            "#[derive(AstEnum, Debug, Clone, PartialEq, Eq, Hash)]\npub enum {} {{",
+            ast_enum.name
+        )
+        .unwrap();
+
+        for (idx, variant) in ast_enum.variants.iter().enumerate() {
+            let node = grammar
+                .nodes
+                .iter()
+                .find(|node| node.ast.as_ref() == Some(&variant.ty))
+                .ok_or_else(|| {
+                    format!(
+                        "`{}::{}` refers to unknown AST type `{}`",
+                        ast_enum.name, variant.name, variant.ty
+                    )
+                })?;
+
+            if idx > 0 {
+                out.push('\n');
+            }
+
+            writeln!(out, "    #[ast(kind = \"{}\")]", node.kind()).unwrap();
+            writeln!(out, "    {}({}),", variant.name, variant.ty).unwrap();
+        }
+
+        out.push_str("}\n");
+    }
+
+    Ok(out)
+}
+
+fn ast_type(node: &Node, name: &str, out: &mut String) {
+    out.push('\n');
+    doc_comment(out, 0, node.doc.as_deref());
+    writeln!(
+        out,
+        "#[repr(transparent)]\n\
+         #[derive(AstType, Debug, Clone, PartialEq, Eq, Hash)]\n\
+         #[ast(kind = \"{}\")]\n\
+         pub struct {}(SyntaxNode);",
+        node.kind(),
+        name
+    )
+    .unwrap();
+
+    if !node.accessors.is_empty() {
+        writeln!(out, "\nimpl {} {{", name).unwrap();
+
+        for (idx, accessor) in node.accessors.iter().enumerate() {
+            if idx > 0 {
+                out.push('\n');
+            }
+
+            doc_comment(out, 4, accessor.doc.as_deref());
+
+            let (ty, body) = if accessor.many {
+                (
+                    format!("AstChildren<{}>", accessor.ty),
+                    "self.children()".to_string(),
+                )
+            } else if accessor.nth == 0 {
+                (
+                    format!("Option<{}>", accessor.ty),
+                    "self.children().next()".to_string(),
+                )
+            } else {
+                (
+                    format!("Option<{}>", accessor.ty),
+                    format!("self.children().nth({})", accessor.nth),
+                )
+            };
+
+            writeln!(
+                out,
+                "    pub fn {}(&self) -> {} {{\n        {}\n    }}",
+                accessor.name, ty, body
+            )
+            .unwrap();
+        }
+
+        out.push_str("}\n");
+    }
+
+    if !node.traits.is_empty() {
+        out.push('\n');
+
+        for tr in &node.traits {
+            writeln!(out, "impl {} for {} {{}}", tr, name).unwrap();
+        }
+    }
+}
@@ -0,0 +1,138 @@
+//! The declarative description of the language in `grammar.toml`.
+
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Grammar {
+    #[serde(rename = "token")]
+    pub tokens: Vec<Token>,
+    #[serde(rename = "keyword")]
+    pub keywords: Vec<Keyword>,
+    #[serde(rename = "node")]
+    pub nodes: Vec<Node>,
+    #[serde(rename = "enum")]
+    pub enums: Vec<AstEnum>,
+}
+
+/// A token produced by the lexer.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Token {
+    pub name: String,
+    pub doc: Option<String>,
+    /// The exact strings that `logos` matches this token with.
+    #[serde(default)]
+    pub token: Vec<String>,
+    /// The regular expressions that `logos` matches this token with.
+    #[serde(default)]
+    pub regex: Vec<String>,
+    /// The lexer callback that consumes the rest of the token after its pattern.
+    pub callback: Option<String>,
+    /// Whether this is the token that `logos` produces for unmatched input.
+    #[serde(default)]
+    pub error: bool,
+    /// Whether this is the token that `logos` produces at the end of the input.
+    #[serde(default)]
+    pub end: bool,
+}
+
+impl Token {
+    pub fn kind(&self) -> String {
+        format!("TOK_{}", shouty_snake_case(&self.name))
+    }
+}
+
+/// A contextual keyword, which is lexed as a name.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Keyword {
+    pub name: String,
+    pub text: String,
+    pub doc: Option<String>,
+    /// Whether variables can be declared with this keyword, e.g. `type t;`.
+    #[serde(default)]
+    pub var_type: bool,
+    /// Whether macro parameters can be declared with this keyword.
+    #[serde(default)]
+    pub param_type: bool,
+    /// Whether this is only a keyword in a macro parameter list, and is otherwise free to be
+    /// used as an identifier.
+    #[serde(default)]
+    pub param_only: bool,
+}
+
+impl Keyword {
+    pub fn kind(&self) -> String {
+        format!("KW_{}", self.text.to_uppercase())
+    }
+}
+
+/// A kind of syntax tree node, and the typed AST wrapper over it.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Node {
+    pub kind: String,
+    /// The name of the AST type that wraps this node, if it has one.
+    pub ast: Option<String>,
+    pub doc: Option<String>,
+    /// The traits implemented by the AST type, which only have provided methods.
+    #[serde(default)]
+    pub traits: Vec<String>,
+    #[serde(default)]
+    pub accessors: Vec<Accessor>,
+}
+
+impl Node {
+    pub fn kind(&self) -> String {
+        format!("NODE_{}", self.kind)
+    }
+}
+
+/// A method of an AST type that finds one or all of its children of a given type.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Accessor {
+    pub name: String,
+    pub ty: String,
+    /// The position of the child amongst the children of the same type.
+    #[serde(default)]
+    pub nth: usize,
+    /// Whether every child of this type is returned, rather than a single child.
+    #[serde(default)]
+    pub many: bool,
+    pub doc: Option<String>,
+}
+
+/// An enum over the AST types of several kinds of node.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AstEnum {
+    pub name: String,
+    pub doc: Option<String>,
+    pub variants: Vec<AstEnumVariant>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AstEnumVariant {
+    pub name: String,
+    pub ty: String,
+}
+
+/// Convert a `CamelCase` name to `SHOUTY_SNAKE_CASE`, e.g. `Ipv4Address` to `IPV4_ADDRESS`.
+fn shouty_snake_case(name: &str) -> String {
+    let mut out = String::new();
+    let mut prev: Option<char> = None;
+
+    for c in name.chars() {
+        if c.is_uppercase() && prev.is_some_and(|p| p.is_lowercase() || p.is_numeric()) {
+            out.push('_');
+        }
+
+        out.extend(c.to_uppercase());
+        prev = Some(c);
+    }
+
+    out
+}
@@ -0,0 +1,249 @@
+//! Generation of the `SyntaxKind`, `TokenKind` and `KeywordKind` enums of `secsp_parser`, and
+//! the tables that map between them.
+
+use std::fmt::Write;
+
+use crate::grammar::{Grammar, Keyword};
+use crate::{doc_comment, header, string_literal};
+
+pub fn generate(grammar: &Grammar) -> String {
+    let mut out = String::new();
+
+    header(&mut out);
+    out.push_str("\nuse std::str::FromStr;\n\nuse logos::Logos;\n\n");
+
+    let mut callbacks: Vec<&str> = grammar
+        .tokens
+        .iter()
+        .filter_map(|token| token.callback.as_deref())
+        .collect();
+
+    callbacks.sort();
+    callbacks.dedup();
+
+    match callbacks.as_slice() {
+        [] => {}
+        [callback] => writeln!(out, "use super::{};\n", callback).unwrap(),
+        callbacks => writeln!(out, "use super::{{{}}};\n", callbacks.join(", ")).unwrap(),
+    }
+
+    syntax_kind(grammar, &mut out);
+    token_kind(grammar, &mut out);
+    keyword_kind(grammar, &mut out);
+
+    out
+}
+
+fn syntax_kind(grammar: &Grammar, out: &mut String) {
+    let mut kinds: Vec<(String, Option<&str>)> = vec![];
+
+    kinds.extend(grammar.keywords.iter().map(|kw| (kw.kind(), None)));
+    kinds.extend(grammar.tokens.iter().map(|token| (token.kind(), None)));
+    kinds.extend(
+        grammar
+            .nodes
+            .iter()
+            .map(|node| (node.kind(), node.doc.as_deref())),
+    );
+    kinds.sort();
+
+    out.push_str(
+        "#[repr(u16)]\n\
+         #[allow(non_camel_case_types)]\n\
+         #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]\n\
+         pub enum SyntaxKind {\n",
+    );
+
+    for (kind, doc) in kinds {
+        doc_comment(out, 4, doc);
+        writeln!(out, "    {},", kind).unwrap();
+    }
+
+    out.push_str("}\n");
+}
+
+fn token_kind(grammar: &Grammar, out: &mut String) {
+    out.push_str(
+        "\n#[derive(Logos, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]\n\
+         pub enum TokenKind {\n",
+    );
+
+    for token in &grammar.tokens {
+        doc_comment(out, 4, token.doc.as_deref());
+
+        for text in &token.token {
+            writeln!(out, "    #[token = {}]", string_literal(text)).unwrap();
+        }
+
+        for regex in &token.regex {
+            writeln!(out, "    #[regex = {}]", string_literal(regex)).unwrap();
+        }
+
+        if let Some(callback) = &token.callback {
+            writeln!(out, "    #[callback = {}]", string_literal(callback)).unwrap();
+        }
+
+        if token.error {
+            out.push_str("    #[error]\n");
+        }
+
+        if token.end {
+            out.push_str("    #[end]\n");
+        }
+
+        writeln!(out, "    {},", token.name).unwrap();
+    }
+
+    out.push_str(
+        "}\n\
+         \n\
+         impl TokenKind {\n\
+         \x20   pub fn syntax_kind(self) -> SyntaxKind {\n\
+         \x20       use self::{SyntaxKind::*, TokenKind::*};\n\
+         \n\
+         \x20       match self {\n",
+    );
+
+    for token in &grammar.tokens {
+        writeln!(out, "            {} => {},", token.name, token.kind()).unwrap();
+    }
+
+    out.push_str("        }\n    }\n}\n");
+}
+
+fn keyword_kind(grammar: &Grammar, out: &mut String) {
+    out.push_str(
+        "\n#[repr(u16)]\n\
+         #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]\n\
+         pub enum KeywordKind {\n",
+    );
+
+    for kw in &grammar.keywords {
+        doc_comment(out, 4, kw.doc.as_deref());
+        writeln!(out, "    {},", kw.name).unwrap();
+    }
+
+    out.push_str(
+        "}\n\
+         \n\
+         impl From<KeywordKind> for SyntaxKind {\n\
+         \x20   fn from(kw: KeywordKind) -> Self {\n\
+         \x20       use self::{KeywordKind::*, SyntaxKind::*};\n\
+         \n\
+         \x20       match kw {\n",
+    );
+
+    for kw in &grammar.keywords {
+        writeln!(out, "            {} => {},", kw.name, kw.kind()).unwrap();
+    }
+
+    out.push_str(
+        "        }\n    }\n}\n\
+         \n\
+         impl FromStr for KeywordKind {\n\
+         \x20   type Err = ();\n\
+         \n\
+         \x20   fn from_str(text: &str) -> Result<Self, Self::Err> {\n\
+         \x20       use self::KeywordKind::*;\n\
+         \n\
+         \x20       let kw = match text {\n",
+    );
+
+    for kw in &grammar.keywords {
+        writeln!(
+            out,
+            "            {} => {},",
+            string_literal(&kw.text),
+            kw.name
+        )
+        .unwrap();
+    }
+
+    out.push_str(
+        "            _ => return Err(()),\n        };\n\n        Ok(kw)\n    }\n}\n\
+         \n\
+         impl AsRef<str> for KeywordKind {\n\
+         \x20   fn as_ref(&self) -> &str {\n\
+         \x20       use self::KeywordKind::*;\n\
+         \n\
+         \x20       match self {\n",
+    );
+
+    for kw in &grammar.keywords {
+        writeln!(
+            out,
+            "            {} => {},",
+            kw.name,
+            string_literal(&kw.text)
+        )
+        .unwrap();
+    }
+
+    out.push_str(
+        "        }\n    }\n}\n\
+         \n\
+         impl KeywordKind {\n\
+         \x20   /// Find the keyword that a parser remapped to the given [kind], if any.\n\
+         \x20   pub fn from_syntax_kind(kind: SyntaxKind) -> Option<Self> {\n\
+         \x20       use self::{KeywordKind::*, SyntaxKind::*};\n\
+         \n\
+         \x20       let kw = match kind {\n",
+    );
+
+    for kw in &grammar.keywords {
+        writeln!(out, "            {} => {},", kw.kind(), kw.name).unwrap();
+    }
+
+    out.push_str("            _ => return None,\n        };\n\n        Some(kw)\n    }\n");
+
+    keyword_predicate(
+        grammar,
+        "is_var_type",
+        "Check if variables can be declared with this keyword.",
+        |kw| kw.var_type,
+        out,
+    );
+    keyword_predicate(
+        grammar,
+        "is_param_type",
+        "Check if this keyword is a type that macro parameters can be declared with.",
+        |kw| kw.param_type,
+        out,
+    );
+    keyword_predicate(
+        grammar,
+        "is_param_only",
+        "Check if this keyword is only a keyword in a macro parameter list, and is otherwise \
+         free to be used as an identifier.",
+        |kw| kw.param_only,
+        out,
+    );
+
+    out.push_str("}\n");
+}
+
+/// Write a method of `KeywordKind` that checks if a keyword is one of those that [filter]
+/// selects.
+fn keyword_predicate<F>(grammar: &Grammar, name: &str, doc: &str, filter: F, out: &mut String)
+where
+    F: Fn(&Keyword) -> bool,
+{
+    let names: Vec<&str> = grammar
+        .keywords
+        .iter()
+        .filter(|kw| filter(kw))
+        .map(|kw| kw.name.as_str())
+        .collect();
+
+    out.push('\n');
+    doc_comment(out, 4, Some(doc));
+    writeln!(
+        out,
+        "    pub fn {}(self) -> bool {{\n        \
+         use self::KeywordKind::*;\n\n        \
+         matches!(self, {})\n    }}",
+        name,
+        names.join(" | ")
+    )
+    .unwrap();
+}
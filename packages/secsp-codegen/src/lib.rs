@@ -0,0 +1,186 @@
+//! Generates the syntax kinds of the parser and the typed AST of `secsp_syntax` from the
+//! description of the language in `libsecsp-parser/grammar.toml`.
+
+use std::error::Error;
+use std::fmt::Write;
+use std::fs;
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use crate::grammar::Grammar;
+
+mod ast;
+mod grammar;
+mod kinds;
+
+pub type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+const GRAMMAR: &str = "libsecsp-parser/grammar.toml";
+const KINDS: &str = "libsecsp-parser/src/syntax/generated.rs";
+const AST: &str = "libsecsp-syntax/src/ast/types/generated.rs";
+
+/// Whether the generated files are written, or checked against what would be written.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Mode {
+    Overwrite,
+    Verify,
+}
+
+/// Generate every file from the grammar.
+pub fn generate(mode: Mode) -> Result<()> {
+    let text = fs::read_to_string(packages_dir().join(GRAMMAR))?;
+    let grammar: Grammar = toml::from_str(&text)?;
+
+    update(KINDS, &reformat(&kinds::generate(&grammar))?, mode)?;
+    update(AST, &reformat(&ast::generate(&grammar)?)?, mode)?;
+
+    Ok(())
+}
+
+/// Format generated code with `rustfmt`, so that it doesn't have to be laid out by hand.
+fn reformat(text: &str) -> Result<String> {
+    let mut rustfmt = Command::new("rustfmt")
+        .args(["--edition", "2018"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .map_err(|e| {
+            format!(
+                "unable to run `rustfmt` to format the generated code ({}), install it with \
+                 `rustup component add rustfmt`",
+                e
+            )
+        })?;
+
+    rustfmt
+        .stdin
+        .take()
+        .expect("rustfmt stdin is piped")
+        .write_all(text.as_bytes())?;
+
+    let output = rustfmt.wait_with_output()?;
+
+    if !output.status.success() {
+        return Err("rustfmt failed to format the generated code".into());
+    }
+
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+fn packages_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("codegen crate is outside of the packages directory")
+        .to_path_buf()
+}
+
+fn update(path: &str, contents: &str, mode: Mode) -> Result<()> {
+    let full_path = packages_dir().join(path);
+
+    if fs::read_to_string(&full_path).ok().as_deref() == Some(contents) {
+        return Ok(());
+    }
+
+    match mode {
+        Mode::Overwrite => {
+            eprintln!("updating {}", path);
+            fs::write(full_path, contents)?;
+            Ok(())
+        }
+        Mode::Verify => Err(format!(
+            "`{}` is out of date, run `cargo run -p secsp-codegen` to regenerate it",
+            path
+        )
+        .into()),
+    }
+}
+
+/// Begin a generated file with a note on where it was generated from.
+fn header(out: &mut String) {
+    out.push_str(&format!(
+        "//! Generated by `secsp-codegen` from `{}`, don't edit it by hand.\n\
+         //! Run `cargo run -p secsp-codegen` to regenerate it instead.\n",
+        GRAMMAR
+    ));
+}
+
+/// Write [doc] as a doc comment at the given indentation, wrapped to 100 columns. Code spans
+/// are never split across lines.
+fn doc_comment(out: &mut String, indent: usize, doc: Option<&str>) {
+    let doc = match doc {
+        Some(doc) => doc,
+        None => return,
+    };
+
+    let prefix = format!("{}///", " ".repeat(indent));
+    let mut words: Vec<String> = vec![];
+
+    for word in doc.split_whitespace() {
+        match words.last_mut() {
+            Some(last) if last.matches('`').count() % 2 == 1 => {
+                last.push(' ');
+                last.push_str(word);
+            }
+            _ => words.push(word.to_string()),
+        }
+    }
+
+    let mut line = prefix.clone();
+
+    for word in words {
+        if line.len() > prefix.len() && line.len() + word.len() + 1 > 100 {
+            writeln!(out, "{}", line).unwrap();
+            line = prefix.clone();
+        }
+
+        line.push(' ');
+        line.push_str(&word);
+    }
+
+    writeln!(out, "{}", line).unwrap();
+}
+
+/// Quote [text] as a Rust string literal.
+fn string_literal(text: &str) -> String {
+    let mut out = String::from("\"");
+
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c => out.push(c),
+        }
+    }
+
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generated_code_is_fresh() {
+        if let Err(e) = generate(Mode::Verify) {
+            panic!("{}", e);
+        }
+    }
+
+    #[test]
+    fn doc_comments_are_wrapped_between_code_spans() {
+        let mut out = String::new();
+        let doc = format!("{} `a b c` d", "word ".repeat(18));
+
+        doc_comment(&mut out, 4, Some(&doc));
+
+        assert_eq!(
+            format!("    ///{}\n    /// `a b c` d\n", " word".repeat(18)),
+            out
+        );
+    }
+}
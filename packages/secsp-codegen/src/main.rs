@@ -0,0 +1,10 @@
+use std::process;
+
+use secsp_codegen::Mode;
+
+fn main() {
+    if let Err(e) = secsp_codegen::generate(Mode::Overwrite) {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
+}
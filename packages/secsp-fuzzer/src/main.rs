@@ -5,7 +5,9 @@ extern crate secsp_syntax;
 fn main() {
     fuzz!(|data: &[u8]| {
         if let Ok(s) = std::str::from_utf8(data) {
-            let _ = secsp_syntax::SourceFile::parse(s);
+            // Parsing must never panic, and the tree must reproduce the input exactly.
+            let parse = secsp_syntax::SourceFile::parse(s);
+            assert_eq!(s, parse.syntax_node().text().to_string());
         }
     });
 }
@@ -12,10 +12,11 @@ use std::{mem, panic};
 use futures::future;
 use jsonrpc_core::{BoxFuture, Result};
 use serde_json::Value;
+use text_unit::{TextRange, TextUnit};
 use tower_lsp::lsp_types::*;
 use tower_lsp::{LanguageServer, LspService, Printer, Server};
 
-use secsp_analysis::{Analysis, AnalysisHost, Cancelable};
+use secsp_analysis::{Analysis, AnalysisHost, Cancelable, TextEdit};
 use std::path::PathBuf;
 
 #[derive(Debug, Default)]
@@ -75,7 +76,7 @@ impl LanguageServer for CspBackend {
         Ok(InitializeResult {
             capabilities: ServerCapabilities {
                 text_document_sync: Some(TextDocumentSyncCapability::Kind(
-                    TextDocumentSyncKind::Full,
+                    TextDocumentSyncKind::Incremental,
                 )),
                 hover_provider: Some(true),
                 ..ServerCapabilities::default()
@@ -101,15 +102,48 @@ impl LanguageServer for CspBackend {
         Box::new(future::ok(None))
     }
 
-    fn did_change(&self, _printer: &Printer, change: DidChangeTextDocumentParams) {
-        if let Ok(path) = change.text_document.uri.to_file_path() {
+    fn did_open(&self, _printer: &Printer, params: DidOpenTextDocumentParams) {
+        if let Ok(path) = params.text_document.uri.to_file_path() {
             self.with_analysis_host(|host| {
-                let content = &change.content_changes;
-                host.add_file(path, content[0].text.clone());
+                host.add_file(path, params.text_document.text);
             })
         }
     }
 
+    fn did_change(&self, _printer: &Printer, change: DidChangeTextDocumentParams) {
+        let path = match change.text_document.uri.to_file_path() {
+            Ok(path) => path,
+            Err(_) => return,
+        };
+
+        self.with_analysis_host(|host| {
+            let file_id = match host.analysis().file_id(path) {
+                Ok(file_id) => file_id,
+                Err(_) => return,
+            };
+
+            // Each change applies to the text left by the change before it.
+            for content in change.content_changes {
+                match content.range {
+                    Some(range) => {
+                        let text = match host.analysis().file_text(file_id) {
+                            Ok(text) => text,
+                            Err(_) => return,
+                        };
+
+                        let range = TextRange::from_to(
+                            offset(&text, range.start),
+                            offset(&text, range.end),
+                        );
+
+                        host.edit_file(file_id, &TextEdit::replace(range, content.text));
+                    }
+                    None => host.update_file(file_id, content.text),
+                }
+            }
+        })
+    }
+
     fn hover(&self, params: TextDocumentPositionParams) -> Self::HoverFuture {
         self.with_analysis(|analysis| {
             let path = params.text_document.uri.to_file_path().unwrap();
@@ -130,6 +164,31 @@ impl LanguageServer for CspBackend {
     }
 }
 
+/// Find the offset of [position] in [text]. Positions count characters in UTF-16 code units.
+fn offset(text: &str, position: Position) -> TextUnit {
+    let line_start: usize = text
+        .split('\n')
+        .take(position.line as usize)
+        .map(|line| line.len() + 1)
+        .sum();
+    let line_start = line_start.min(text.len());
+    let line = text[line_start..].split('\n').next().unwrap_or_default();
+
+    let mut utf16_len = 0;
+    let mut len = 0;
+
+    for c in line.chars() {
+        if utf16_len >= position.character as usize {
+            break;
+        }
+
+        utf16_len += c.len_utf16();
+        len += c.len_utf8();
+    }
+
+    TextUnit::from_usize(line_start + len)
+}
+
 fn main() {
     env_logger::init();
 
@@ -0,0 +1,39 @@
+//! Compare two files of symbolic expressions for structural equality, ignoring any differences
+//! in whitespace. Used by the smoke tests to compare generated CIL against the expected output.
+
+extern crate symbolic_expressions;
+
+use std::fs;
+use std::process;
+
+use symbolic_expressions::parser::parse_str;
+use symbolic_expressions::Sexp;
+
+fn read_sexp(path: &str) -> Sexp {
+    let text = fs::read_to_string(path).unwrap_or_else(|e| {
+        eprintln!("unable to read {}: {}", path, e);
+        process::exit(2);
+    });
+
+    parse_str(&format!("({})", text)).unwrap_or_else(|e| {
+        eprintln!("unable to parse {}: {}", path, e);
+        process::exit(2);
+    })
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    if args.len() != 2 {
+        eprintln!("usage: sexp_cmp <expected> <actual>");
+        process::exit(2);
+    }
+
+    let expected = read_sexp(&args[0]);
+    let actual = read_sexp(&args[1]);
+
+    if expected != actual {
+        eprintln!("{} and {} differ", args[0], args[1]);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,122 @@
+//! Code generation for the Common Intermediate Language (CIL) consumed by the SELinux
+//! userspace toolchain.
+//!
+//! Each item in a [SourceFile] is lowered to one or more CIL statements, represented as
+//! symbolic expressions, which are then pretty-printed as CIL source text.
+
+use std::fmt;
+
+use symbolic_expressions::Sexp;
+use text_unit::TextRange;
+
+use secsp_analysis::macros::MacroTable;
+use secsp_analysis::toggles::ToggleTable;
+use secsp_syntax::ast::SourceFile;
+
+use self::lower::Lowering;
+
+mod lower;
+mod printer;
+
+pub use self::printer::print;
+
+/// An error encountered while lowering a syntactically valid source file, usually a construct
+/// that has no equivalent in CIL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodegenError {
+    message: String,
+    range: TextRange,
+}
+
+impl CodegenError {
+    pub fn new<S: Into<String>>(message: S, range: TextRange) -> Self {
+        CodegenError {
+            message: message.into(),
+            range,
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn range(&self) -> TextRange {
+        self.range
+    }
+}
+
+impl fmt::Display for CodegenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+/// Lower all of the items in the given [file] to a list of CIL statements, resolving macro
+/// calls against [macros] and the booleans and tunables in conditions against [toggles].
+pub fn generate_cil(
+    file: &SourceFile,
+    macros: &MacroTable,
+    toggles: &ToggleTable,
+) -> Result<Vec<Sexp>, Vec<CodegenError>> {
+    Lowering::new(macros, toggles).lower_source_file(file)
+}
+
+#[cfg(test)]
+mod tests {
+    use symbolic_expressions::parser::parse_str;
+
+    use super::*;
+
+    fn parse_cil(text: &str) -> Sexp {
+        parse_str(&format!("({})", text)).expect("invalid CIL")
+    }
+
+    pub(crate) fn assert_cil(csp: &str, expected_cil: &str) {
+        let parse = SourceFile::parse(csp);
+        assert!(parse.errors().is_empty(), "{:#?}", parse.errors());
+
+        let macros = MacroTable::new(vec![parse.tree()]);
+        let toggles = ToggleTable::new(vec![parse.tree()]);
+        let statements =
+            generate_cil(&parse.tree(), &macros, &toggles).expect("code generation failed");
+        let actual_cil = print(&statements);
+
+        assert_eq!(
+            parse_cil(expected_cil),
+            parse_cil(&actual_cil),
+            "generated CIL:\n{}",
+            actual_cil
+        );
+    }
+
+    pub(crate) fn assert_cil_error(csp: &str, message: &str) {
+        let parse = SourceFile::parse(csp);
+        assert!(parse.errors().is_empty(), "{:#?}", parse.errors());
+
+        let macros = MacroTable::new(vec![parse.tree()]);
+        let toggles = ToggleTable::new(vec![parse.tree()]);
+        let errors =
+            generate_cil(&parse.tree(), &macros, &toggles).expect_err("code generation succeeded");
+        assert!(
+            errors.iter().any(|e| e.message() == message),
+            "{:#?}",
+            errors
+        );
+    }
+
+    #[test]
+    fn block_inheritance() {
+        assert_cil(
+            include_str!("../../../tests/block_inheritance.csp"),
+            include_str!("../../../tests/block_inheritance.cil"),
+        );
+    }
+
+    #[test]
+    fn symbol_modifiers() {
+        assert_cil(
+            include_str!("../../../tests/symbol_modifiers.csp"),
+            include_str!("../../../tests/symbol_modifiers.cil"),
+        );
+    }
+}
@@ -0,0 +1,2123 @@
+use std::collections::HashMap;
+
+use symbolic_expressions::Sexp;
+
+use secsp_analysis::macros::{self, MacroTable};
+use secsp_analysis::toggles::ToggleTable;
+use secsp_syntax::ast::*;
+
+use crate::codegen::CodegenError;
+
+pub(super) fn atom<S: Into<String>>(value: S) -> Sexp {
+    Sexp::String(value.into())
+}
+
+pub(super) fn list(items: Vec<Sexp>) -> Sexp {
+    Sexp::List(items)
+}
+
+/// Wrap [value] in a list, unless it is a list already.
+fn as_list(value: Sexp) -> Sexp {
+    match value {
+        Sexp::List(_) => value,
+        _ => list(vec![value]),
+    }
+}
+
+#[derive(Default)]
+pub(super) struct Lowering {
+    errors: Vec<CodegenError>,
+    /// The kinds of the variables declared in each enclosing container, innermost last.
+    scopes: Vec<HashMap<String, KeywordKind>>,
+    /// The macros that calls are resolved against to bind their arguments.
+    macros: MacroTable,
+    /// The booleans and tunables that conditions are resolved against.
+    toggles: ToggleTable,
+    /// The macro whose default argument values are being lowered, which paths are qualified
+    /// relative to.
+    default_of: Option<MacroDef>,
+}
+
+impl Lowering {
+    pub(super) fn new(macros: &MacroTable, toggles: &ToggleTable) -> Self {
+        Lowering {
+            macros: macros.clone(),
+            toggles: toggles.clone(),
+            ..Lowering::default()
+        }
+    }
+
+    pub(super) fn lower_source_file(
+        mut self,
+        file: &SourceFile,
+    ) -> Result<Vec<Sexp>, Vec<CodegenError>> {
+        let mut out = vec![];
+        self.push_scope(file.items());
+        self.lower_items(file.items(), &mut out);
+        self.scopes.pop();
+
+        if self.errors.is_empty() {
+            Ok(out)
+        } else {
+            Err(self.errors)
+        }
+    }
+
+    fn error<N: AstNode, S: Into<String>>(&mut self, node: &N, message: S) {
+        self.errors
+            .push(CodegenError::new(message, node.syntax().text_range()));
+    }
+
+    /// Report an error against [node] if a required [value] is missing.
+    fn require<N: AstNode, T>(&mut self, node: &N, value: Option<T>, what: &str) -> Option<T> {
+        if value.is_none() {
+            self.error(node, format!("missing {}", what));
+        }
+
+        value
+    }
+
+    /// Record the variables declared by [items] in a new scope. Declarations are collected
+    /// up-front, since CIL doesn't require a declaration to precede its uses.
+    fn push_scope<I: Iterator<Item = Item>>(&mut self, items: I) {
+        let scope = items
+            .filter_map(|item| match item {
+                Item::Variable(var) => Some((var.name()?, var.var_kind()?)),
+                Item::TypeAlias(alias) => Some((alias.name()?, KeywordKind::Type)),
+                _ => None,
+            })
+            .collect();
+
+        self.scopes.push(scope);
+    }
+
+    /// Find the kind of the variable that an unqualified [path] refers to.
+    fn resolve_kind(&self, path: &PathExpr) -> Option<KeywordKind> {
+        let segments = path.segments();
+
+        if path.is_global() || segments.len() != 1 {
+            return None;
+        }
+
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(&segments[0]).cloned())
+    }
+
+    fn lower_items<I>(&mut self, items: I, out: &mut Vec<Sexp>)
+    where
+        I: Iterator<Item = Item>,
+    {
+        for item in items {
+            self.lower_item(item, out);
+        }
+    }
+
+    fn lower_item(&mut self, item: Item, out: &mut Vec<Sexp>) {
+        match item {
+            Item::Container(container) => self.lower_container(&container, out),
+            Item::Macro(macro_def) => self.lower_macro(&macro_def, out),
+            Item::Variable(var) => self.lower_variable(&var, out),
+            Item::TypeAlias(alias) => self.lower_type_alias(&alias, out),
+            Item::TypeBounds(bounds) => {
+                let parent = self.require(&bounds, bounds.parent(), "parent type");
+                let child = self.require(&bounds, bounds.child(), "child type");
+                let operands = [parent.map(Expr::Path), child.map(Expr::Path)];
+
+                self.lower_statement("typebounds", &operands, out)
+            }
+            Item::Permissive(permissive) => {
+                let domain = self.require(&permissive, permissive.domain(), "domain");
+                self.lower_statement("typepermissive", &[domain.map(Expr::Path)], out)
+            }
+            Item::Class(class) => self.lower_class(&class, out),
+            Item::Common(common) => self.lower_common(&common, out),
+            Item::Conditional(cond) => self.lower_conditional(&cond, out),
+            Item::SetModifier(stmt) => self.lower_set_modifier(&stmt, out),
+            Item::MacroCall(call) => self.lower_macro_call(&call, out),
+            Item::Order(stmt) => self.lower_order(&stmt, out),
+            Item::Import(stmt) => {
+                // The imported file is lowered separately, so only its placement is checked here.
+                if self.scopes.len() > 1 {
+                    self.error(&stmt, "imports must be at the top level of a file");
+                }
+            }
+            Item::RoleType(rule) => {
+                let operands = [
+                    self.require(&rule, rule.role(), "role"),
+                    self.require(&rule, rule.types(), "types"),
+                ];
+
+                self.lower_statement("roletype", &operands, out)
+            }
+            Item::RoleAllow(rule) => {
+                let operands = [
+                    self.require(&rule, rule.source(), "source role"),
+                    self.require(&rule, rule.target(), "target role"),
+                ];
+
+                self.lower_statement("roleallow", &operands, out)
+            }
+            Item::RoleTransition(rule) => {
+                let operands = [
+                    self.require(&rule, rule.source(), "source role"),
+                    self.require(&rule, rule.target(), "target type"),
+                    self.require(&rule, rule.class(), "class"),
+                    self.require(&rule, rule.result_role(), "result role"),
+                ];
+
+                self.lower_statement("roletransition", &operands, out)
+            }
+            Item::UserRole(stmt) => {
+                let operands = [
+                    self.require(&stmt, stmt.user(), "user"),
+                    self.require(&stmt, stmt.roles(), "roles"),
+                ];
+
+                self.lower_statement("userrole", &operands, out)
+            }
+            Item::UserLevel(stmt) => {
+                let level = stmt.level();
+                self.lower_user_level("userlevel", &stmt, stmt.user(), level, out)
+            }
+            Item::UserRange(stmt) => {
+                let range = stmt.range();
+                self.lower_user_level("userrange", &stmt, stmt.user(), range, out)
+            }
+            Item::SensitivityCategory(stmt) => self.lower_sensitivity_category(&stmt, out),
+            Item::Constraint(stmt) => self.lower_constraint(&stmt, out),
+            Item::FileContext(stmt) => self.lower_file_context(&stmt, out),
+            Item::FsUse(stmt) => self.lower_fs_use(&stmt, out),
+            Item::Genfscon(stmt) => self.lower_genfscon(&stmt, out),
+            Item::PortContext(stmt) => self.lower_port_context(&stmt, out),
+            Item::NetifContext(stmt) => self.lower_netif_context(&stmt, out),
+            Item::NodeContext(stmt) => self.lower_node_context(&stmt, out),
+            Item::IbPkeyContext(stmt) => self.lower_ibpkey_context(&stmt, out),
+            Item::IbEndportContext(stmt) => self.lower_ibendport_context(&stmt, out),
+            Item::SidContext(stmt) => self.lower_sid_context(&stmt, out),
+            Item::TeRule(rule) => self.lower_te_rule(&rule, out),
+            Item::XpermRule(rule) => self.lower_xperm_rule(&rule, out),
+            Item::TypeTransition(rule) => {
+                let name = rule.object_name();
+                self.lower_type_rule("typetransition", &rule, name, out)
+            }
+            Item::TypeChange(rule) => self.lower_type_rule("typechange", &rule, None, out),
+            Item::TypeMember(rule) => self.lower_type_rule("typemember", &rule, None, out),
+        }
+    }
+
+    fn lower_container(&mut self, container: &ContainerDef, out: &mut Vec<Sexp>) {
+        let (name, kind) = match (
+            self.require(container, container.name(), "container name"),
+            self.require(container, container.container_kind(), "container type"),
+        ) {
+            (Some(name), Some(kind)) => (name, kind),
+            _ => return,
+        };
+
+        let keyword = match kind {
+            ContainerKind::Block => "block",
+            ContainerKind::Optional => "optional",
+            ContainerKind::Extension => "in",
+        };
+
+        let mut statements = vec![atom(keyword), atom(name.clone())];
+
+        if kind != ContainerKind::Block && container.extends_list().is_some() {
+            self.error(container, "only blocks can extend other blocks");
+        }
+
+        if container.is_abstract() {
+            statements.push(list(vec![atom("blockabstract"), atom(name)]));
+        }
+
+        if let Some(extends_list) = container.extends_list() {
+            for parent in extends_list.paths() {
+                statements.push(list(vec![atom("blockinherit"), atom(parent.path())]));
+            }
+        }
+
+        self.push_scope(container.items());
+        self.lower_items(container.items(), &mut statements);
+        self.scopes.pop();
+        out.push(list(statements));
+    }
+
+    fn lower_macro(&mut self, macro_def: &MacroDef, out: &mut Vec<Sexp>) {
+        let name = match self.require(macro_def, macro_def.name(), "macro name") {
+            Some(name) => name,
+            None => return,
+        };
+
+        let mut params = vec![];
+        let param_list = macro_def.param_list();
+        self.push_scope(macro_def.items());
+
+        for param in param_list.iter().flat_map(|list| list.params()) {
+            let param_kind = param.param_kind();
+            let kind = param_kind.and_then(|kind| self.lower_param_kind(kind));
+
+            match (kind, param_kind, param.name()) {
+                (Some(kind), Some(param_kind), Some(name)) => {
+                    params.push(list(vec![atom(kind), atom(name.clone())]));
+
+                    if let Some(scope) = self.scopes.last_mut() {
+                        scope.insert(name, param_kind);
+                    }
+                }
+                _ => self.error(&param, "invalid macro parameter"),
+            }
+        }
+
+        let mut statements = vec![atom("macro"), atom(name), list(params)];
+        self.lower_items(macro_def.items(), &mut statements);
+        self.scopes.pop();
+        out.push(list(statements));
+    }
+
+    fn lower_param_kind(&mut self, kind: KeywordKind) -> Option<&'static str> {
+        use secsp_syntax::ast::KeywordKind::*;
+
+        let cil_kind = match kind {
+            Type | TypeAttribute => "type",
+            Role | RoleAttribute => "role",
+            User | UserAttribute => "user",
+            Sensitivity => "sensitivity",
+            Category => "category",
+            CategorySet => "categoryset",
+            Level => "level",
+            LevelRange => "levelrange",
+            Class => "class",
+            ClassPermission => "classpermission",
+            IpAddress => "ipaddr",
+            String => "string",
+            Name => "name",
+            Bool => "boolean",
+            _ => return None,
+        };
+
+        Some(cil_kind)
+    }
+
+    fn lower_variable(&mut self, var: &VariableDef, out: &mut Vec<Sexp>) {
+        use secsp_syntax::ast::KeywordKind::*;
+
+        let (name, kind) = match (
+            self.require(var, var.name(), "variable name"),
+            self.require(var, var.var_kind(), "variable type"),
+        ) {
+            (Some(name), Some(kind)) => (name, kind),
+            _ => return,
+        };
+
+        let (declaration, initializer) = match kind {
+            Type => ("type", None),
+            TypeAttribute => ("typeattribute", Some("typeattributeset")),
+            Role => ("role", None),
+            RoleAttribute => ("roleattribute", Some("roleattributeset")),
+            User => ("user", None),
+            UserAttribute => ("userattribute", Some("userattributeset")),
+            Sensitivity => ("sensitivity", None),
+            Category => ("category", None),
+            Sid => ("sid", None),
+            ClassPermission => ("classpermission", Some("classpermissionset")),
+            SensitivityAlias | CategoryAlias => {
+                let (declaration, actual) = match kind {
+                    SensitivityAlias => ("sensitivityalias", "sensitivityaliasactual"),
+                    _ => ("categoryalias", "categoryaliasactual"),
+                };
+
+                let value = self.require(var, var.initializer(), "aliased name");
+
+                if let Some(value) = value.and_then(|value| self.lower_expr(&value)) {
+                    out.push(list(vec![atom(declaration), atom(name.clone())]));
+                    out.push(list(vec![atom(actual), atom(name), value]));
+                }
+
+                return;
+            }
+            Bool | Tunable => {
+                let declaration = if kind == Bool { "boolean" } else { "tunable" };
+                let value = self.require(var, var.initializer(), "initial value");
+
+                match value {
+                    Some(Expr::Literal(ref literal))
+                        if literal.kind() == Some(LiteralKind::Boolean) =>
+                    {
+                        out.push(list(vec![
+                            atom(declaration),
+                            atom(name),
+                            atom(literal.text()),
+                        ]));
+                    }
+                    Some(value) => self.error(&value, "expected `true` or `false`"),
+                    None => {}
+                }
+
+                return;
+            }
+            LevelRange => {
+                let range = self.require(var, var.initializer(), "level range initializer");
+
+                if let Some(range) = range.and_then(|range| self.lower_level_range(&range)) {
+                    out.push(list(vec![atom("levelrange"), atom(name), range]));
+                }
+
+                return;
+            }
+            _ => {
+                self.error(
+                    var,
+                    format!("{} cannot be used as a variable", kind.as_ref()),
+                );
+                return;
+            }
+        };
+
+        out.push(list(vec![atom(declaration), atom(name.clone())]));
+
+        match (var.initializer(), initializer) {
+            (Some(value), Some(initializer)) => {
+                if let Some(value) = self.lower_expr(&value) {
+                    out.push(list(vec![atom(initializer), atom(name), value]));
+                }
+            }
+            (Some(value), None) => self.error(
+                &value,
+                format!("a {} cannot have an initializer", kind.as_ref()),
+            ),
+            (None, _) => {}
+        }
+    }
+
+    fn lower_type_alias(&mut self, alias: &TypeAliasDef, out: &mut Vec<Sexp>) {
+        let name = self.require(alias, alias.name(), "alias name");
+        let actual = self.require(alias, alias.actual(), "aliased type");
+
+        if let (Some(name), Some(actual)) = (name, actual) {
+            out.push(list(vec![atom("typealias"), atom(name.clone())]));
+            out.push(list(vec![
+                atom("typealiasactual"),
+                atom(name),
+                atom(actual.path()),
+            ]));
+        }
+    }
+
+    fn lower_class(&mut self, class: &ClassDef, out: &mut Vec<Sexp>) {
+        let name = match self.require(class, class.name(), "class name") {
+            Some(name) => name,
+            None => return,
+        };
+
+        let permissions = match class.permissions() {
+            Some(permissions) => self.lower_list(&permissions),
+            None => Some(list(vec![])),
+        };
+
+        let commons: Vec<PathExpr> = class
+            .extends_list()
+            .into_iter()
+            .flat_map(|list| list.paths())
+            .collect();
+
+        if commons.len() > 1 {
+            self.error(
+                class,
+                "a class can only inherit permissions from a single common",
+            );
+            return;
+        }
+
+        if let Some(permissions) = permissions {
+            out.push(list(vec![atom("class"), atom(name.clone()), permissions]));
+        }
+
+        for common in commons {
+            out.push(list(vec![
+                atom("classcommon"),
+                atom(name.clone()),
+                atom(common.path()),
+            ]));
+        }
+    }
+
+    fn lower_common(&mut self, common: &CommonDef, out: &mut Vec<Sexp>) {
+        let name = self.require(common, common.name(), "common name");
+        let permissions = self.require(common, common.permissions(), "permissions");
+
+        if let (Some(name), Some(permissions)) = (name, permissions) {
+            if let Some(permissions) = self.lower_list(&permissions) {
+                out.push(list(vec![atom("common"), atom(name), permissions]));
+            }
+        }
+    }
+
+    fn lower_order(&mut self, stmt: &OrderStmt, out: &mut Vec<Sexp>) {
+        let keyword = match stmt.order_kind() {
+            Some(KeywordKind::ClassOrder) => "classorder",
+            Some(KeywordKind::SensitivityOrder) => "sensitivityorder",
+            Some(KeywordKind::CategoryOrder) => "categoryorder",
+            Some(KeywordKind::SidOrder) => "sidorder",
+            _ => {
+                self.error(stmt, "unknown order statement");
+                return;
+            }
+        };
+
+        let items = self.require(stmt, stmt.items(), "ordered items");
+
+        if let Some(items) = items.and_then(|items| self.lower_list(&items)) {
+            out.push(list(vec![atom(keyword), items]));
+        }
+    }
+
+    fn lower_sensitivity_category(&mut self, stmt: &SensitivityCategoryStmt, out: &mut Vec<Sexp>) {
+        let sensitivity = self.require(stmt, stmt.sensitivity(), "sensitivity");
+        let categories = self.require(stmt, stmt.categories(), "categories");
+
+        if let (Some(sensitivity), Some(categories)) = (sensitivity, categories) {
+            if let Some(categories) = self.lower_categories(&categories) {
+                out.push(list(vec![
+                    atom("sensitivitycategory"),
+                    atom(sensitivity.path()),
+                    categories,
+                ]));
+            }
+        }
+    }
+
+    fn lower_conditional(&mut self, cond: &ConditionalStmt, out: &mut Vec<Sexp>) {
+        // CIL has no `else if` branches, so each branch of the chain becomes a separate
+        // `booleanif` that is only true when all of the preceding conditions are false.
+        let keyword = match self.conditional_keyword(cond) {
+            Some(keyword) => keyword,
+            None => return,
+        };
+
+        let mut negated_conditions: Option<Sexp> = None;
+        let mut branch = Some(cond.clone());
+
+        while let Some(cond) = branch.take() {
+            let condition = match self
+                .require(&cond, cond.condition(), "condition")
+                .and_then(|expr| self.lower_condition_expr(&expr))
+            {
+                Some(condition) => condition,
+                None => return,
+            };
+
+            let is_first_branch = negated_conditions.is_none();
+            let effective_condition = match &negated_conditions {
+                Some(negated) => list(vec![atom("and"), negated.clone(), condition.clone()]),
+                None => condition.clone(),
+            };
+
+            let negated_condition = list(vec![atom("not"), condition]);
+            negated_conditions = Some(match negated_conditions.take() {
+                Some(negated) => list(vec![atom("and"), negated, negated_condition]),
+                None => negated_condition,
+            });
+
+            let mut statement = vec![atom(keyword), effective_condition];
+            let mut true_branch = vec![atom("true")];
+
+            if let Some(block) = cond.then_block() {
+                self.lower_items(block.items(), &mut true_branch);
+            }
+
+            statement.push(list(true_branch));
+
+            match cond.else_block() {
+                // The false branch of a later condition in the chain would also run when an
+                // earlier condition is true, so the final `else` needs its own `booleanif`.
+                Some(block) if !is_first_branch => {
+                    out.push(list(statement));
+
+                    let mut else_branch = vec![atom("true")];
+                    self.lower_items(block.items(), &mut else_branch);
+
+                    let else_condition = negated_conditions.clone().expect("no preceding branch");
+                    out.push(list(vec![atom(keyword), else_condition, list(else_branch)]));
+                }
+                Some(block) => {
+                    let mut false_branch = vec![atom("false")];
+                    self.lower_items(block.items(), &mut false_branch);
+                    statement.push(list(false_branch));
+                    out.push(list(statement));
+                }
+                None => out.push(list(statement)),
+            }
+
+            branch = cond.else_if();
+        }
+    }
+
+    /// Choose between a `booleanif` and a `tunableif` based on the toggles referenced by the
+    /// conditions of an if / else-if chain.
+    fn conditional_keyword(&mut self, cond: &ConditionalStmt) -> Option<&'static str> {
+        let mut has_booleans = false;
+        let mut has_tunables = false;
+        let mut has_undeclared = false;
+        let mut branch = Some(cond.clone());
+
+        while let Some(cond) = branch.take() {
+            let paths = cond
+                .condition()
+                .into_iter()
+                .flat_map(|condition| descendants(condition.syntax()).filter_map(PathExpr::cast));
+
+            for path in paths {
+                match self.toggles.resolve(&path) {
+                    Some(KeywordKind::Tunable) => has_tunables = true,
+                    Some(_) => has_booleans = true,
+                    None => {
+                        let message = format!("undeclared boolean or tunable `{}`", path.path());
+                        self.error(&path, message);
+                        has_undeclared = true;
+                    }
+                }
+            }
+
+            branch = cond.else_if();
+        }
+
+        if has_undeclared {
+            return None;
+        }
+
+        match (has_booleans, has_tunables) {
+            (true, true) => {
+                self.error(cond, "a condition can't mix booleans and tunables");
+                None
+            }
+            (false, true) => Some("tunableif"),
+            _ => Some("booleanif"),
+        }
+    }
+
+    fn lower_condition_expr(&mut self, expr: &Expr) -> Option<Sexp> {
+        match expr {
+            Expr::Path(_) | Expr::Paren(_) | Expr::Prefix(_) | Expr::Binary(_) => {
+                self.lower_expr(expr)
+            }
+            _ => {
+                self.error(expr, "expected a boolean expression");
+                None
+            }
+        }
+    }
+
+    fn lower_set_modifier(&mut self, stmt: &SetModifierStmt, out: &mut Vec<Sexp>) {
+        let target = self.require(stmt, stmt.target(), "set name");
+        let op = self.require(stmt, stmt.op(), "operator");
+        let value = self.require(stmt, stmt.value(), "value");
+
+        let (target, op, value) = match (target, op, value) {
+            (Some(target), Some(op), Some(value)) => (target, op, value),
+            _ => return,
+        };
+
+        if op == SetModifierOp::Remove {
+            self.error(stmt, "removing members from a set is not supported by CIL");
+            return;
+        }
+
+        // The kind of set is taken from its declaration, or from a cast of the value when the
+        // set is declared elsewhere, e.g. `attr |= (type) a` adds members to a type attribute.
+        let kind = match (self.resolve_kind(&target), &value) {
+            (Some(kind), _) => kind,
+            (None, Expr::Cast(cast)) => match cast.target_kind() {
+                Some(KeywordKind::Type) => KeywordKind::TypeAttribute,
+                Some(KeywordKind::Role) => KeywordKind::RoleAttribute,
+                Some(KeywordKind::User) => KeywordKind::UserAttribute,
+                Some(kind) => kind,
+                None => return,
+            },
+            (None, _) => {
+                let message = format!("unable to determine the type of {}", target.path());
+                self.error(&target, message);
+                return;
+            }
+        };
+
+        let statement = match kind {
+            KeywordKind::TypeAttribute => "typeattributeset",
+            KeywordKind::RoleAttribute => "roleattributeset",
+            KeywordKind::UserAttribute => "userattributeset",
+            KeywordKind::ClassPermission => "classpermissionset",
+            _ => {
+                let message = format!("a {} cannot have members added to it", kind.as_ref());
+                self.error(&target, message);
+                return;
+            }
+        };
+
+        if let Some(value) = self.lower_expr(&value) {
+            out.push(list(vec![atom(statement), atom(target.path()), value]));
+        }
+    }
+
+    fn lower_macro_call(&mut self, call: &MacroCall, out: &mut Vec<Sexp>) {
+        let path = match self.require(call, call.path(), "macro name") {
+            Some(path) => path,
+            None => return,
+        };
+
+        // CIL only has positional arguments, so calls to known macros are passed an argument
+        // for every parameter, in order.
+        let arguments: Vec<(Expr, Option<MacroDef>)> = match self.macros.resolve(&path).cloned() {
+            Some(macro_def) => match macros::bind_arguments(&macro_def, call) {
+                Ok(bound) => bound
+                    .into_iter()
+                    .map(|(param, value)| {
+                        if param.default_value().as_ref() == Some(&value) {
+                            (value, Some(macro_def.clone()))
+                        } else {
+                            (value, None)
+                        }
+                    })
+                    .collect(),
+                Err(diagnostics) => {
+                    self.errors.extend(diagnostics.iter().map(|diagnostic| {
+                        CodegenError::new(diagnostic.message(), diagnostic.range())
+                    }));
+                    return;
+                }
+            },
+            None => {
+                if let Some(named) = call.arguments().find(|arg| arg.name().is_some()) {
+                    let message = format!(
+                        "unable to find macro `{}` to pass arguments by name",
+                        path.path()
+                    );
+                    self.error(&named, message);
+                    return;
+                }
+
+                call.arguments()
+                    .filter_map(|arg| arg.value())
+                    .map(|value| (value, None))
+                    .collect()
+            }
+        };
+
+        let mut statement = vec![atom("call"), atom(path.path())];
+
+        // Default values are written within the macro's container, so their paths are
+        // qualified to refer to the same symbols from the call site.
+        let arguments: Option<Vec<Sexp>> = arguments
+            .into_iter()
+            .map(|(argument, default_of)| {
+                self.default_of = default_of;
+                let lowered = self.lower_expr(&argument);
+                self.default_of = None;
+                lowered
+            })
+            .collect();
+
+        let arguments = match arguments {
+            Some(arguments) => arguments,
+            None => return,
+        };
+
+        if !arguments.is_empty() {
+            statement.push(list(arguments));
+        }
+
+        out.push(list(statement));
+    }
+
+    fn lower_te_rule(&mut self, rule: &TeRule, out: &mut Vec<Sexp>) {
+        let keyword = match rule.rule_kind() {
+            TeRuleKind::Allow => "allow",
+            TeRuleKind::AuditAllow => "auditallow",
+            TeRuleKind::DontAudit => "dontaudit",
+            TeRuleKind::NeverAllow => "neverallow",
+        };
+
+        let source = self.require(rule, rule.source(), "source type");
+        let target = self.require(rule, rule.target(), "target type");
+        let access_vector = self.require(rule, rule.access_vector(), "access vector");
+
+        if let (Some(source), Some(target), Some(access_vector)) = (source, target, access_vector) {
+            let source = self.lower_expr(&source);
+            let target = self.lower_expr(&target);
+            let access_vector = self.lower_access_vector(&access_vector);
+
+            if let (Some(source), Some(target), Some(access_vector)) =
+                (source, target, access_vector)
+            {
+                out.push(list(vec![atom(keyword), source, target, access_vector]));
+            }
+        }
+    }
+
+    fn lower_xperm_rule(&mut self, rule: &XpermRule, out: &mut Vec<Sexp>) {
+        let keyword = match rule.rule_kind() {
+            TeRuleKind::Allow => "allowx",
+            TeRuleKind::AuditAllow => "auditallowx",
+            TeRuleKind::DontAudit => "dontauditx",
+            TeRuleKind::NeverAllow => "neverallowx",
+        };
+
+        // A missing operation kind has already been reported by `require`.
+        let operation = match self.require(rule, rule.operation_kind(), "operation kind") {
+            Some(KeywordKind::Ioctl) => "ioctl",
+            Some(kind) => {
+                let message = format!("unsupported extended permission kind `{}`", kind.as_ref());
+                self.error(rule, message);
+                return;
+            }
+            None => return,
+        };
+
+        let source = self.require(rule, rule.source(), "source type");
+        let target = self.require(rule, rule.target(), "target type");
+        let class = self.require(rule, rule.class(), "class");
+
+        let (source, target, class) = match (source, target, class) {
+            (Some(source), Some(target), Some(class)) => (source, target, class),
+            _ => return,
+        };
+
+        let source = self.lower_expr(&source);
+        let target = self.lower_expr(&target);
+        let class = self.lower_expr(&class);
+
+        // Lower every range before giving up, so that each invalid one is reported.
+        let ranges: Vec<_> = rule
+            .ranges()
+            .map(|range| self.lower_xperm_range(&range))
+            .collect();
+
+        let ranges: Option<Vec<_>> = ranges.into_iter().collect();
+
+        if let (Some(source), Some(target), Some(class), Some(ranges)) =
+            (source, target, class, ranges)
+        {
+            let permissions = list(vec![atom(operation), class, list(ranges)]);
+            out.push(list(vec![atom(keyword), source, target, permissions]));
+        }
+    }
+
+    /// Lower a single number of an extended permission rule, or a `(range low high)` expression.
+    fn lower_xperm_range(&mut self, range: &XpermRange) -> Option<Sexp> {
+        let low = self.require(range, range.low(), "number")?;
+
+        match self.lower_integer_range("operation", &low, range.high(), 0xffff)? {
+            Sexp::List(mut bounds) => {
+                bounds.insert(0, atom("range"));
+                Some(Sexp::List(bounds))
+            }
+            number => Some(number),
+        }
+    }
+
+    /// Lower a statement made up of a [keyword] followed by a list of required operands. The
+    /// statement is omitted if any of the operands are missing.
+    fn lower_statement(&mut self, keyword: &str, operands: &[Option<Expr>], out: &mut Vec<Sexp>) {
+        let mut statement = vec![atom(keyword)];
+
+        for operand in operands {
+            match operand
+                .as_ref()
+                .and_then(|operand| self.lower_expr(operand))
+            {
+                Some(operand) => statement.push(operand),
+                None => return,
+            }
+        }
+
+        out.push(list(statement));
+    }
+
+    /// Lower a `user_level` or `user_range` statement, which only differ in whether the user is
+    /// given a single level or a range of levels.
+    fn lower_user_level<N: AstNode>(
+        &mut self,
+        keyword: &str,
+        stmt: &N,
+        user: Option<Expr>,
+        level: Option<Expr>,
+        out: &mut Vec<Sexp>,
+    ) {
+        let user = self.require(stmt, user, "user");
+        let level = self.require(stmt, level, "level");
+
+        if let (Some(user), Some(level)) = (user, level) {
+            let user = self.lower_expr(&user);
+            let level = if keyword == "userrange" {
+                self.lower_level_range(&level)
+            } else {
+                self.lower_level(&level)
+            };
+
+            if let (Some(user), Some(level)) = (user, level) {
+                out.push(list(vec![atom(keyword), user, level]));
+            }
+        }
+    }
+
+    fn lower_type_rule<R: TypeRule>(
+        &mut self,
+        keyword: &str,
+        rule: &R,
+        object_name: Option<LiteralExpr>,
+        out: &mut Vec<Sexp>,
+    ) {
+        let source = self.require(rule, rule.source(), "source type");
+        let target = self.require(rule, rule.target(), "target type");
+        let class = self.require(rule, rule.class(), "class");
+        let result = self.require(rule, rule.result_type(), "result type");
+
+        let operands = match (source, target, class, result) {
+            (Some(source), Some(target), Some(class), Some(result)) => {
+                let object_name = object_name.map(Expr::Literal);
+                let mut operands = vec![source, target, class];
+
+                operands.extend(object_name);
+                operands.push(result);
+                operands
+            }
+            _ => return,
+        };
+
+        let operands: Option<Vec<Sexp>> = operands
+            .iter()
+            .map(|operand| self.lower_expr(operand))
+            .collect();
+
+        if let Some(operands) = operands {
+            let mut statement = vec![atom(keyword)];
+            statement.extend(operands);
+            out.push(list(statement));
+        }
+    }
+
+    fn lower_constraint(&mut self, stmt: &ConstraintStmt, out: &mut Vec<Sexp>) {
+        let (keyword, has_permissions) = match stmt.constraint_kind() {
+            Some(KeywordKind::Constrain) => ("constrain", true),
+            Some(KeywordKind::MlsConstrain) => ("mlsconstrain", true),
+            Some(KeywordKind::ValidateTrans) => ("validatetrans", false),
+            Some(KeywordKind::MlsValidateTrans) => ("mlsvalidatetrans", false),
+            _ => return,
+        };
+
+        let target = self.require(stmt, stmt.access_vector(), "class");
+        let expr = self.require(stmt, stmt.expr(), "constraint expression");
+
+        let (target, expr) = match (target, expr) {
+            (Some(target), Some(expr)) => (target, expr),
+            _ => return,
+        };
+
+        let target = if has_permissions {
+            self.lower_access_vector(&target)
+        } else {
+            self.lower_expr(&target)
+        };
+
+        let expr = self.lower_constraint_expr(&expr, keyword);
+
+        if let (Some(target), Some(expr)) = (target, expr) {
+            out.push(list(vec![atom(keyword), target, expr]));
+        }
+    }
+
+    fn lower_constraint_expr(&mut self, expr: &ConstraintExpr, keyword: &str) -> Option<Sexp> {
+        match expr {
+            ConstraintExpr::Binary(binary) => {
+                let op = match self.require(binary, binary.op(), "operator")? {
+                    BinaryOp::LogicalAnd => "and",
+                    BinaryOp::LogicalOr => "or",
+                    _ => {
+                        self.error(binary, "expected `&&` or `||` in a constraint expression");
+                        return None;
+                    }
+                };
+
+                let lhs = self.require(binary, binary.lhs(), "left operand")?;
+                let rhs = self.require(binary, binary.rhs(), "right operand")?;
+                let lhs = self.lower_constraint_expr(&lhs, keyword);
+                let rhs = self.lower_constraint_expr(&rhs, keyword);
+
+                Some(list(vec![atom(op), lhs?, rhs?]))
+            }
+            ConstraintExpr::Not(not) => {
+                let operand = self.require(not, not.operand(), "operand")?;
+                let operand = self.lower_constraint_expr(&operand, keyword)?;
+
+                Some(list(vec![atom("not"), operand]))
+            }
+            ConstraintExpr::Paren(paren) => {
+                let inner = self.require(paren, paren.expr(), "expression")?;
+                self.lower_constraint_expr(&inner, keyword)
+            }
+            ConstraintExpr::Comparison(comparison) => {
+                let op = match self.require(comparison, comparison.op(), "operator")? {
+                    ConstraintOp::Eq => "eq",
+                    ConstraintOp::Neq => "neq",
+                    ConstraintOp::Dom => "dom",
+                    ConstraintOp::DomBy => "domby",
+                    ConstraintOp::Incomp => "incomp",
+                };
+
+                let lhs = self.require(comparison, comparison.lhs(), "left operand")?;
+                let rhs = self.require(comparison, comparison.rhs(), "right operand")?;
+                let name = lhs.path();
+
+                // The components of the old (1), new (2) and, for validatetrans, the process
+                // (3) security contexts that a constraint can refer to.
+                let levels = ["l1", "l2", "h1", "h2"];
+                let is_level = levels.contains(&name.as_str());
+                let is_valid = match name.as_str() {
+                    "u1" | "u2" | "r1" | "r2" | "t1" | "t2" => true,
+                    "u3" | "r3" | "t3" => keyword.ends_with("validatetrans"),
+                    _ => is_level && keyword.starts_with("mls"),
+                };
+
+                if !is_valid {
+                    self.error(
+                        &lhs,
+                        format!("`{}` can't be used in a {} statement", name, keyword),
+                    );
+                    return None;
+                }
+
+                // CIL only compares the dominance of one level with another.
+                let is_level_rhs = match &rhs {
+                    Expr::Path(path) => levels.contains(&path.path().as_str()),
+                    _ => false,
+                };
+
+                if matches!(op, "dom" | "domby" | "incomp") && !(is_level && is_level_rhs) {
+                    self.error(
+                        comparison,
+                        format!(
+                            "`{}` can only compare the levels `l1`, `l2`, `h1` and `h2`",
+                            op
+                        ),
+                    );
+                    return None;
+                }
+
+                Some(list(vec![atom(op), atom(name), self.lower_expr(&rhs)?]))
+            }
+        }
+    }
+
+    fn lower_file_context(&mut self, stmt: &FileContextStmt, out: &mut Vec<Sexp>) {
+        let path = self.require(stmt, stmt.path(), "path");
+        let context = self.require(stmt, stmt.context(), "context");
+
+        let (path, context) = match (path, context) {
+            (Some(path), Some(context)) => (path, context),
+            _ => return,
+        };
+
+        let file_type = match stmt.file_type() {
+            Some(file_type) => {
+                let name = file_type.path();
+                let known = [
+                    "file", "dir", "char", "block", "socket", "pipe", "symlink", "any",
+                ];
+
+                if !known.contains(&name.as_str()) {
+                    self.error(&file_type, format!("unknown file type `{}`", name));
+                    return;
+                }
+
+                name
+            }
+            None => "any".to_string(),
+        };
+
+        if let (Some(path), Some(context)) =
+            (self.lower_literal(&path), self.lower_context(&context))
+        {
+            out.push(list(vec![atom("filecon"), path, atom(file_type), context]));
+        }
+    }
+
+    fn lower_fs_use(&mut self, stmt: &FsUseStmt, out: &mut Vec<Sexp>) {
+        let kind = match stmt.fs_use_kind() {
+            Some(KeywordKind::FsUseXattr) => "xattr",
+            Some(KeywordKind::FsUseTask) => "task",
+            Some(KeywordKind::FsUseTrans) => "trans",
+            _ => return,
+        };
+
+        let fs_type = self.require(stmt, stmt.fs_type(), "filesystem type");
+        let context = self.require(stmt, stmt.context(), "context");
+
+        if let (Some(fs_type), Some(context)) = (fs_type, context) {
+            if let Some(context) = self.lower_context(&context) {
+                out.push(list(vec![
+                    atom("fsuse"),
+                    atom(kind),
+                    atom(fs_type.path()),
+                    context,
+                ]));
+            }
+        }
+    }
+
+    fn lower_genfscon(&mut self, stmt: &GenfsconStmt, out: &mut Vec<Sexp>) {
+        let fs_type = self.require(stmt, stmt.fs_type(), "filesystem type");
+        let path = self.require(stmt, stmt.path(), "path");
+        let context = self.require(stmt, stmt.context(), "context");
+
+        if let (Some(fs_type), Some(path), Some(context)) = (fs_type, path, context) {
+            if let (Some(path), Some(context)) =
+                (self.lower_literal(&path), self.lower_context(&context))
+            {
+                out.push(list(vec![
+                    atom("genfscon"),
+                    atom(fs_type.path()),
+                    path,
+                    context,
+                ]));
+            }
+        }
+    }
+
+    fn lower_port_context(&mut self, stmt: &PortContextStmt, out: &mut Vec<Sexp>) {
+        let protocol = self.require(stmt, stmt.protocol(), "protocol");
+        let low = self.require(stmt, stmt.low_port(), "port");
+        let context = self.require(stmt, stmt.context(), "context");
+
+        let (protocol, low, context) = match (protocol, low, context) {
+            (Some(protocol), Some(low), Some(context)) => (protocol, low, context),
+            _ => return,
+        };
+
+        let name = protocol.path();
+
+        if !["tcp", "udp", "dccp", "sctp"].contains(&name.as_str()) {
+            self.error(&protocol, format!("unknown protocol `{}`", name));
+            return;
+        }
+
+        let ports = self.lower_integer_range("port", &low, stmt.high_port(), 0xffff);
+        let context = self.lower_context(&context);
+
+        if let (Some(ports), Some(context)) = (ports, context) {
+            out.push(list(vec![atom("portcon"), atom(name), ports, context]));
+        }
+    }
+
+    fn lower_netif_context(&mut self, stmt: &NetifContextStmt, out: &mut Vec<Sexp>) {
+        let interface = self.require(stmt, stmt.interface(), "network interface");
+        let interface_context = self.require(stmt, stmt.interface_context(), "interface context");
+        let packet_context = self.require(stmt, stmt.packet_context(), "packet context");
+
+        if let (Some(interface), Some(interface_context), Some(packet_context)) =
+            (interface, interface_context, packet_context)
+        {
+            let interface_context = self.lower_context(&interface_context);
+            let packet_context = self.lower_context(&packet_context);
+
+            if let (Some(interface_context), Some(packet_context)) =
+                (interface_context, packet_context)
+            {
+                out.push(list(vec![
+                    atom("netifcon"),
+                    atom(interface.path()),
+                    interface_context,
+                    packet_context,
+                ]));
+            }
+        }
+    }
+
+    fn lower_node_context(&mut self, stmt: &NodeContextStmt, out: &mut Vec<Sexp>) {
+        let address = self.require(stmt, stmt.address(), "address");
+        let mask = self.require(stmt, stmt.mask(), "mask");
+        let context = self.require(stmt, stmt.context(), "context");
+
+        let (address, mask, context) = match (address, mask, context) {
+            (Some(address), Some(mask), Some(context)) => (address, mask, context),
+            _ => return,
+        };
+
+        if address.kind() != mask.kind() {
+            self.error(stmt, "address and mask must be in the same address family");
+            return;
+        }
+
+        if let Some(context) = self.lower_context(&context) {
+            out.push(list(vec![
+                atom("nodecon"),
+                list(vec![atom(address.text())]),
+                list(vec![atom(mask.text())]),
+                context,
+            ]));
+        }
+    }
+
+    fn lower_ibpkey_context(&mut self, stmt: &IbPkeyContextStmt, out: &mut Vec<Sexp>) {
+        let subnet_prefix = self.require(stmt, stmt.subnet_prefix(), "subnet prefix");
+        let low = self.require(stmt, stmt.low_pkey(), "partition key");
+        let context = self.require(stmt, stmt.context(), "context");
+
+        let (subnet_prefix, low, context) = match (subnet_prefix, low, context) {
+            (Some(subnet_prefix), Some(low), Some(context)) => (subnet_prefix, low, context),
+            _ => return,
+        };
+
+        let pkeys = self.lower_integer_range("partition key", &low, stmt.high_pkey(), 0xffff);
+        let context = self.lower_context(&context);
+
+        if let (Some(pkeys), Some(context)) = (pkeys, context) {
+            out.push(list(vec![
+                atom("ibpkeycon"),
+                atom(subnet_prefix.text()),
+                pkeys,
+                context,
+            ]));
+        }
+    }
+
+    fn lower_ibendport_context(&mut self, stmt: &IbEndportContextStmt, out: &mut Vec<Sexp>) {
+        let device = self.require(stmt, stmt.device(), "device name");
+        let port = self.require(stmt, stmt.port(), "port");
+        let context = self.require(stmt, stmt.context(), "context");
+
+        let (device, port, context) = match (device, port, context) {
+            (Some(device), Some(port), Some(context)) => (device, port, context),
+            _ => return,
+        };
+
+        let port = self.lower_integer_range("port", &port, None, 0xff);
+        let context = self.lower_context(&context);
+
+        if let (Some(port), Some(context)) = (port, context) {
+            out.push(list(vec![
+                atom("ibendportcon"),
+                atom(device.path()),
+                port,
+                context,
+            ]));
+        }
+    }
+
+    fn lower_sid_context(&mut self, stmt: &SidContextStmt, out: &mut Vec<Sexp>) {
+        let sid = self.require(stmt, stmt.sid(), "initial security identifier");
+        let context = self.require(stmt, stmt.context(), "context");
+
+        if let (Some(sid), Some(context)) = (sid, context) {
+            if let Some(context) = self.lower_context(&context) {
+                out.push(list(vec![atom("sidcontext"), atom(sid.path()), context]));
+            }
+        }
+    }
+
+    /// Lower a single integer or a `(low high)` pair, checking that each is no greater than
+    /// [max] and that the range isn't reversed.
+    fn lower_integer_range(
+        &mut self,
+        what: &str,
+        low: &LiteralExpr,
+        high: Option<LiteralExpr>,
+        max: u64,
+    ) -> Option<Sexp> {
+        let low_value = self.integer_value(what, low, max)?;
+
+        let high = match high {
+            Some(high) => high,
+            None => return Some(atom(low.text())),
+        };
+
+        let high_value = self.integer_value(what, &high, max)?;
+
+        if low_value > high_value {
+            let message = format!(
+                "invalid {} range: {} is greater than {}",
+                what,
+                low.text(),
+                high.text()
+            );
+
+            self.error(&high, message);
+            return None;
+        }
+
+        Some(list(vec![atom(low.text()), atom(high.text())]))
+    }
+
+    fn integer_value(&mut self, what: &str, literal: &LiteralExpr, max: u64) -> Option<u64> {
+        let text = literal.text();
+        let value = if text.starts_with("0x") || text.starts_with("0X") {
+            u64::from_str_radix(&text[2..], 16).ok()
+        } else {
+            text.parse().ok()
+        };
+
+        match value {
+            Some(value) if value <= max => Some(value),
+            _ => {
+                self.error(literal, format!("{} {} is out of range", what, text));
+                None
+            }
+        }
+    }
+
+    /// Lower a literal, quoting its value if it's a string or path. CIL strings can't contain
+    /// escape sequences, so strings are written out with their escapes replaced.
+    fn lower_literal(&mut self, literal: &LiteralExpr) -> Option<Sexp> {
+        match literal.kind() {
+            Some(LiteralKind::String) => {
+                let value = match literal.unescape() {
+                    Some(value) => value,
+                    None => {
+                        self.error(literal, "invalid string literal");
+                        return None;
+                    }
+                };
+
+                if value.contains('"') {
+                    self.error(literal, "strings can't contain quotes in CIL");
+                    return None;
+                }
+
+                Some(atom(format!("\"{}\"", value)))
+            }
+            Some(LiteralKind::PathPattern) => Some(atom(format!("\"{}\"", literal.text()))),
+            _ => Some(atom(literal.text())),
+        }
+    }
+
+    /// Lower the `user:role:type:range` label of a labeling statement.
+    fn lower_context(&mut self, expr: &Expr) -> Option<Sexp> {
+        match expr {
+            Expr::Context(_) => self.lower_expr(expr),
+            _ => {
+                self.error(expr, "expected a security context");
+                None
+            }
+        }
+    }
+
+    /// Lower a `class (permissions)` expression, or a reference to a named class permission set.
+    fn lower_access_vector(&mut self, expr: &Expr) -> Option<Sexp> {
+        match expr {
+            Expr::Set(_) | Expr::Path(_) => self.lower_expr(expr),
+            _ => {
+                self.error(expr, "expected a class and permission set");
+                None
+            }
+        }
+    }
+
+    fn lower_list(&mut self, items: &ListExpr) -> Option<Sexp> {
+        self.lower_expr(&Expr::List(items.clone()))
+    }
+
+    pub(super) fn lower_expr(&mut self, expr: &Expr) -> Option<Sexp> {
+        match expr {
+            Expr::Path(path) => match &self.default_of {
+                Some(macro_def) => Some(atom(macros::qualify_default_path(macro_def, path))),
+                None => Some(atom(path.path())),
+            },
+            Expr::Literal(literal) => self.lower_literal(literal),
+            Expr::Cast(cast) => {
+                let operand = self.require(cast, cast.operand(), "operand")?;
+                self.lower_expr(&operand)
+            }
+            Expr::List(items) => {
+                let items: Option<Vec<Sexp>> =
+                    items.items().map(|item| self.lower_expr(&item)).collect();
+
+                items.map(list)
+            }
+            Expr::Paren(paren) => {
+                let inner = self.require(paren, paren.expr(), "expression")?;
+                self.lower_expr(&inner)
+            }
+            Expr::Prefix(prefix) => {
+                let operand = self.require(prefix, prefix.operand(), "operand")?;
+                let operand = self.lower_expr(&operand)?;
+
+                Some(list(vec![atom("not"), operand]))
+            }
+            Expr::Binary(binary) => {
+                let op = match self.require(binary, binary.op(), "operator")? {
+                    BinaryOp::LogicalAnd | BinaryOp::BitwiseAnd => "and",
+                    BinaryOp::LogicalOr | BinaryOp::BitwiseOr => "or",
+                    BinaryOp::BitwiseXor => "xor",
+                };
+
+                let lhs = self.require(binary, binary.lhs(), "left operand")?;
+                let rhs = self.require(binary, binary.rhs(), "right operand")?;
+                let lhs = self.lower_expr(&lhs)?;
+                let rhs = self.lower_expr(&rhs)?;
+
+                Some(list(vec![atom(op), lhs, rhs]))
+            }
+            Expr::Set(set) => {
+                let name = self.require(set, set.name(), "set name")?;
+                let subset = self.require(set, set.subset(), "subset")?;
+                let name = self.lower_expr(&name)?;
+                let subset = self.lower_expr(&subset)?;
+
+                Some(list(vec![name, as_list(subset)]))
+            }
+            Expr::Level(level) => {
+                let sensitivity = self.require(level, level.sensitivity(), "sensitivity")?;
+                let categories = self.require(level, level.categories(), "categories")?;
+                let sensitivity = self.lower_expr(&sensitivity)?;
+                let categories = self.lower_categories(&categories)?;
+
+                Some(list(vec![sensitivity, categories]))
+            }
+            Expr::LevelRange(_) => self.lower_level_range(expr),
+            Expr::CategoryRange(range) => {
+                let low = self.require(range, range.low(), "low category")?;
+                let high = self.require(range, range.high(), "high category")?;
+                let low = self.lower_expr(&low)?;
+                let high = self.lower_expr(&high)?;
+
+                Some(list(vec![atom("range"), low, high]))
+            }
+            Expr::Context(context) => {
+                let user = self.require(context, context.user(), "user")?;
+                let role = self.require(context, context.role(), "role")?;
+                let type_ = self.require(context, context.type_(), "type")?;
+
+                let mut components = vec![
+                    self.lower_expr(&user)?,
+                    self.lower_expr(&role)?,
+                    self.lower_expr(&type_)?,
+                ];
+
+                if let Some(range) = context.level_range() {
+                    components.push(self.lower_level_range(&range)?);
+                }
+
+                Some(list(components))
+            }
+        }
+    }
+
+    /// Lower a set of categories, which is always a list in CIL.
+    fn lower_categories(&mut self, expr: &Expr) -> Option<Sexp> {
+        match expr {
+            Expr::CategoryRange(_) => self.lower_expr(expr),
+            _ => Some(as_list(self.lower_expr(expr)?)),
+        }
+    }
+
+    /// Lower a `sensitivity:categories` level, or a sensitivity on its own. CIL requires
+    /// anonymous levels to be wrapped in a list, so `s0` becomes `(s0)`.
+    fn lower_level(&mut self, expr: &Expr) -> Option<Sexp> {
+        match expr {
+            Expr::Path(path) => Some(list(vec![atom(path.path())])),
+            Expr::Level(_) => self.lower_expr(expr),
+            _ => {
+                self.error(expr, "expected a level");
+                None
+            }
+        }
+    }
+
+    /// Lower a `low-high` level range, a reference to a named level range, or a single level
+    /// that is shorthand for a range with the same low and high level.
+    fn lower_level_range(&mut self, expr: &Expr) -> Option<Sexp> {
+        match expr {
+            Expr::LevelRange(range) => {
+                let low = self.require(range, range.low(), "low level")?;
+                let high = self.require(range, range.high(), "high level")?;
+                let low = self.lower_level(&low)?;
+                let high = self.lower_level(&high)?;
+
+                Some(list(vec![low, high]))
+            }
+            Expr::Path(path) if self.resolve_kind(path) == Some(KeywordKind::LevelRange) => {
+                Some(atom(path.path()))
+            }
+            _ => {
+                let level = self.lower_level(expr)?;
+                Some(list(vec![level.clone(), level]))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::codegen::tests::{assert_cil, assert_cil_error};
+
+    #[test]
+    fn abstract_block_with_parents() {
+        assert_cil(
+            "abstract block a extends b, c { type t; }",
+            "(block a (blockabstract a) (blockinherit b) (blockinherit c) (type t))",
+        );
+    }
+
+    #[test]
+    fn optional_and_extension_containers() {
+        assert_cil(
+            "optional o { role r; } in a { user u; }",
+            "(optional o (role r)) (in a (user u))",
+        );
+    }
+
+    #[test]
+    fn optional_with_parents() {
+        assert_cil_error(
+            "optional o extends b {}",
+            "only blocks can extend other blocks",
+        );
+    }
+
+    #[test]
+    fn macro_with_params() {
+        assert_cil(
+            "macro m(type t, role_attribute r) { allow t t : file (read); }",
+            "(macro m ((type t) (role r)) (allow t t (file (read))))",
+        );
+    }
+
+    #[test]
+    fn macro_with_value_params() {
+        assert_cil(
+            "macro m(category_set c, level l, ip_address ip, string s, name n) {}",
+            "(macro m ((categoryset c) (level l) (ipaddr ip) (string s) (name n)))",
+        );
+    }
+
+    #[test]
+    fn path_pattern_arguments() {
+        assert_cil(
+            "macro m(string a, string b) {} m(/tmp, /usr/lib(64)?/.*);",
+            r#"
+            (macro m ((string a) (string b)))
+            (call m ("/tmp" "/usr/lib(64)?/.*"))
+            "#,
+        );
+    }
+
+    #[test]
+    fn attribute_with_initializer() {
+        assert_cil(
+            "type_attribute a = (b c) | ~d;",
+            "(typeattribute a) (typeattributeset a (or (b c) (not d)))",
+        );
+    }
+
+    #[test]
+    fn type_with_initializer() {
+        assert_cil_error("type t = a;", "a type cannot have an initializer");
+    }
+
+    #[test]
+    fn level_range() {
+        assert_cil(
+            "level_range r = s0-s1:c0..c5;",
+            "(levelrange r ((s0) (s1 (range c0 c5))))",
+        );
+    }
+
+    #[test]
+    fn mls_declarations() {
+        assert_cil(
+            r#"
+            sensitivity s0;
+            sensitivity s1;
+            sensitivity_alias unclassified = s0;
+            sensitivity_order (s0, s1);
+            category c0;
+            category c1;
+            category_alias first = c0;
+            category_order (c0, c1);
+            sensitivity_category s0 c0;
+            sensitivity_category s1 c0..c1;
+            "#,
+            r#"
+            (sensitivity s0)
+            (sensitivity s1)
+            (sensitivityalias unclassified)
+            (sensitivityaliasactual unclassified s0)
+            (sensitivityorder (s0 s1))
+            (category c0)
+            (category c1)
+            (categoryalias first)
+            (categoryaliasactual first c0)
+            (categoryorder (c0 c1))
+            (sensitivitycategory s0 (c0))
+            (sensitivitycategory s1 (range c0 c1))
+            "#,
+        );
+    }
+
+    #[test]
+    fn classes_and_commons() {
+        assert_cil(
+            r#"
+            common file (read write);
+            class file extends file (execute);
+            class dir extends file;
+            class process (fork);
+            class_order (file, dir, process);
+            "#,
+            r#"
+            (common file (read write))
+            (class file (execute))
+            (classcommon file file)
+            (class dir ())
+            (classcommon dir file)
+            (class process (fork))
+            (classorder (file dir process))
+            "#,
+        );
+    }
+
+    #[test]
+    fn class_permissions() {
+        assert_cil(
+            r#"
+            class_permission read_file = file (read getattr);
+            read_file |= dir (search);
+            macro m(class_permission p) {}
+            "#,
+            r#"
+            (classpermission read_file)
+            (classpermissionset read_file (file (read getattr)))
+            (classpermissionset read_file (dir (search)))
+            (macro m ((classpermission p)))
+            "#,
+        );
+    }
+
+    #[test]
+    fn type_aliases_bounds_and_permissive_domains() {
+        assert_cil(
+            r#"
+            type httpd_t;
+            type httpd_child_t;
+            type_alias web_t = httpd_t;
+            type_bounds httpd_t httpd_child_t;
+            permissive httpd_child_t;
+            "#,
+            r#"
+            (type httpd_t)
+            (type httpd_child_t)
+            (typealias web_t)
+            (typealiasactual web_t httpd_t)
+            (typebounds httpd_t httpd_child_t)
+            (typepermissive httpd_child_t)
+            "#,
+        );
+    }
+
+    #[test]
+    fn type_alias_resolves_as_type() {
+        assert_cil_error(
+            "type t; type_alias a = t; a |= t;",
+            "a type cannot have members added to it",
+        );
+    }
+
+    #[test]
+    fn imports_are_omitted() {
+        assert_cil("import \"base.csp\"; type t;", "(type t)");
+    }
+
+    #[test]
+    fn nested_import() {
+        assert_cil_error(
+            "block b { import \"base.csp\"; }",
+            "imports must be at the top level of a file",
+        );
+    }
+
+    #[test]
+    fn te_rules() {
+        assert_cil(
+            r#"
+            allow a b : file (read write);
+            audit_allow a b : file (read);
+            dont_audit a b : dir (search);
+            never_allow a b : read_file_perms;
+            "#,
+            r#"
+            (allow a b (file (read write)))
+            (auditallow a b (file (read)))
+            (dontaudit a b (dir (search)))
+            (neverallow a b read_file_perms)
+            "#,
+        );
+    }
+
+    #[test]
+    fn xperm_rules() {
+        assert_cil(
+            r#"
+            allow_xperm a b : tcp_socket ioctl (0x8900, 0x8910-0x891f);
+            audit_allow_xperm a b : tcp_socket ioctl 0x8900;
+            dont_audit_xperm a b : udp_socket ioctl 0x0-0xffff;
+            never_allow_xperm a b : tcp_socket ioctl (0x8927);
+            "#,
+            r#"
+            (allowx a b (ioctl tcp_socket (0x8900 (range 0x8910 0x891f))))
+            (auditallowx a b (ioctl tcp_socket (0x8900)))
+            (dontauditx a b (ioctl udp_socket ((range 0x0 0xffff))))
+            (neverallowx a b (ioctl tcp_socket (0x8927)))
+            "#,
+        );
+    }
+
+    #[test]
+    fn xperm_rule_with_invalid_range() {
+        assert_cil_error(
+            "allow_xperm a b : tcp_socket ioctl 0x8910-0x8900;",
+            "invalid operation range: 0x8910 is greater than 0x8900",
+        );
+        assert_cil_error(
+            "allow_xperm a b : tcp_socket ioctl 0x10000;",
+            "operation 0x10000 is out of range",
+        );
+    }
+
+    #[test]
+    fn set_modifiers() {
+        assert_cil(
+            r#"
+            r |= a;
+            role_attribute r;
+            block b {
+                type_attribute r;
+                r |= c;
+            }
+            "#,
+            r#"
+            (roleattributeset r a)
+            (roleattribute r)
+            (block b (typeattribute r) (typeattributeset r c))
+            "#,
+        );
+    }
+
+    #[test]
+    fn set_modifier_on_macro_param() {
+        assert_cil(
+            "macro m(user_attribute u) { u |= a; }",
+            "(macro m ((user u)) (userattributeset u a))",
+        );
+    }
+
+    #[test]
+    fn set_modifier_with_cast() {
+        assert_cil(
+            "a |= (role) b; c |= (user_attribute) (d e);",
+            "(roleattributeset a b) (userattributeset c (d e))",
+        );
+    }
+
+    #[test]
+    fn set_modifier_removal() {
+        assert_cil_error(
+            "type_attribute a; a -= b;",
+            "removing members from a set is not supported by CIL",
+        );
+    }
+
+    #[test]
+    fn set_modifier_on_unknown_set() {
+        assert_cil_error("a |= b;", "unable to determine the type of a");
+    }
+
+    #[test]
+    fn type_rules() {
+        assert_cil(
+            r#"
+            type_transition a b : process c;
+            type_transition a b : file "name" c;
+            type_change a b : file c;
+            type_member a b : dir c;
+            "#,
+            r#"
+            (typetransition a b process c)
+            (typetransition a b file "name" c)
+            (typechange a b file c)
+            (typemember a b dir c)
+            "#,
+        );
+    }
+
+    #[test]
+    fn role_rules() {
+        assert_cil(
+            r#"
+            role_attribute ra;
+            ra |= r;
+            role_type r (a b);
+            role_allow r r2;
+            role_transition r a : process r2;
+            "#,
+            r#"
+            (roleattribute ra)
+            (roleattributeset ra r)
+            (roletype r (a b))
+            (roleallow r r2)
+            (roletransition r a process r2)
+            "#,
+        );
+    }
+
+    #[test]
+    fn user_statements() {
+        assert_cil(
+            r#"
+            user u;
+            user_attribute ua;
+            ua |= u;
+            user_role u (r1 r2);
+            user_level u s0;
+            user_range u s0-s1:c0..c5;
+            "#,
+            r#"
+            (user u)
+            (userattribute ua)
+            (userattributeset ua u)
+            (userrole u (r1 r2))
+            (userlevel u (s0))
+            (userrange u ((s0) (s1 (range c0 c5))))
+            "#,
+        );
+    }
+
+    #[test]
+    fn constraints() {
+        assert_cil(
+            r#"
+            constrain file (read write) u1 == u2 && !(r1 != r2 || t1 == (a b));
+            mls_constrain cp (l1 dom h2);
+            validate_trans file t3 == t1;
+            mls_validate_trans file l1 domby h2;
+            "#,
+            r#"
+            (constrain (file (read write)) (and (eq u1 u2) (not (or (neq r1 r2) (eq t1 (a b))))))
+            (mlsconstrain cp (dom l1 h2))
+            (validatetrans file (eq t3 t1))
+            (mlsvalidatetrans file (domby l1 h2))
+            "#,
+        );
+    }
+
+    #[test]
+    fn constraint_with_invalid_operand() {
+        assert_cil_error(
+            "constrain file (read) l1 dom l2;",
+            "`l1` can't be used in a constrain statement",
+        );
+    }
+
+    #[test]
+    fn dominance_of_non_level_operands() {
+        assert_cil_error(
+            "mls_constrain file (read) u1 dom u2;",
+            "`dom` can only compare the levels `l1`, `l2`, `h1` and `h2`",
+        );
+        assert_cil_error(
+            "mls_constrain file (read) l1 incomp s0;",
+            "`incomp` can only compare the levels `l1`, `l2`, `h1` and `h2`",
+        );
+    }
+
+    #[test]
+    fn initial_sids() {
+        assert_cil(
+            r#"
+            sid kernel;
+            sid security;
+            sid_order (kernel, security);
+            sid_context kernel u:r:kernel_t:s0;
+            "#,
+            r#"
+            (sid kernel)
+            (sid security)
+            (sidorder (kernel security))
+            (sidcontext kernel (u r kernel_t ((s0) (s0))))
+            "#,
+        );
+    }
+
+    #[test]
+    fn sid_context_without_context() {
+        assert_cil_error(
+            "sid_context kernel kernel_t;",
+            "expected a security context",
+        );
+    }
+
+    #[test]
+    fn file_labeling() {
+        assert_cil(
+            r#"
+            file_context "/usr/bin(/.*)?" u:r:bin_t:s0;
+            file_context "/tmp" dir u:r:tmp_t:s0;
+            fs_use_xattr ext4 u:r:fs_t:s0;
+            fs_use_task pipefs u:r:fs_t:s0;
+            fs_use_trans tmpfs u:r:tmpfs_t:s0;
+            genfscon proc "/" u:r:proc_t:s0;
+            "#,
+            r#"
+            (filecon "/usr/bin(/.*)?" any (u r bin_t ((s0) (s0))))
+            (filecon "/tmp" dir (u r tmp_t ((s0) (s0))))
+            (fsuse xattr ext4 (u r fs_t ((s0) (s0))))
+            (fsuse task pipefs (u r fs_t ((s0) (s0))))
+            (fsuse trans tmpfs (u r tmpfs_t ((s0) (s0))))
+            (genfscon proc "/" (u r proc_t ((s0) (s0))))
+            "#,
+        );
+    }
+
+    #[test]
+    fn file_labeling_with_path_patterns() {
+        assert_cil(
+            r#"
+            file_context /usr/lib(64)?/.*\.so u:r:lib_t:s0;
+            file_context "/etc/[^/]*\\.conf" file u:r:etc_t:s0;
+            genfscon proc / u:r:proc_t:s0;
+            "#,
+            r#"
+            (filecon "/usr/lib(64)?/.*\.so" any (u r lib_t ((s0) (s0))))
+            (filecon "/etc/[^/]*\.conf" file (u r etc_t ((s0) (s0))))
+            (genfscon proc "/" (u r proc_t ((s0) (s0))))
+            "#,
+        );
+    }
+
+    #[test]
+    fn string_with_escaped_quote() {
+        assert_cil_error(
+            r#"file_context "/tmp/\"a\"" u:r:tmp_t:s0;"#,
+            "strings can't contain quotes in CIL",
+        );
+    }
+
+    #[test]
+    fn file_context_with_unknown_file_type() {
+        assert_cil_error(
+            "file_context \"/tmp\" folder u:r:t;",
+            "unknown file type `folder`",
+        );
+    }
+
+    #[test]
+    fn network_labeling() {
+        assert_cil(
+            r#"
+            port_context tcp 80 u:r:http_port_t:s0;
+            port_context udp 8000-8080 u:r:app_port_t:s0;
+            netif_context eth0 u:r:netif_t:s0 u:r:packet_t:s0;
+            node_context 192.168.0.0 255.255.255.0 u:r:node_t:s0;
+            node_context fe80:: ffff:ffff:ffff:ffff:: u:r:node_t:s0;
+            ibpkey_context fe80:: 0x8000-0xffff u:r:pkey_t:s0;
+            ibendport_context mlx4_0 1 u:r:endport_t:s0;
+            "#,
+            r#"
+            (portcon tcp 80 (u r http_port_t ((s0) (s0))))
+            (portcon udp (8000 8080) (u r app_port_t ((s0) (s0))))
+            (netifcon eth0 (u r netif_t ((s0) (s0))) (u r packet_t ((s0) (s0))))
+            (nodecon (192.168.0.0) (255.255.255.0) (u r node_t ((s0) (s0))))
+            (nodecon (fe80::) (ffff:ffff:ffff:ffff::) (u r node_t ((s0) (s0))))
+            (ibpkeycon fe80:: (0x8000 0xffff) (u r pkey_t ((s0) (s0))))
+            (ibendportcon mlx4_0 1 (u r endport_t ((s0) (s0))))
+            "#,
+        );
+    }
+
+    #[test]
+    fn reversed_port_range() {
+        assert_cil_error(
+            "port_context tcp 90-80 u:r:t;",
+            "invalid port range: 90 is greater than 80",
+        );
+    }
+
+    #[test]
+    fn port_out_of_range() {
+        assert_cil_error(
+            "port_context tcp 65536 u:r:t;",
+            "port 65536 is out of range",
+        );
+    }
+
+    #[test]
+    fn node_context_with_mixed_address_families() {
+        assert_cil_error(
+            "node_context 10.0.0.0 ffff:: u:r:t;",
+            "address and mask must be in the same address family",
+        );
+    }
+
+    #[test]
+    fn booleans_and_tunables() {
+        assert_cil(
+            r#"
+            bool allow_write = true;
+            tunable enable_debug = false;
+            if allow_write { allow a b : file (write); }
+            if !enable_debug { allow a b : file (read); }
+            "#,
+            r#"
+            (boolean allow_write true)
+            (tunable enable_debug false)
+            (booleanif allow_write (true (allow a b (file (write)))))
+            (tunableif (not enable_debug) (true (allow a b (file (read)))))
+            "#,
+        );
+    }
+
+    #[test]
+    fn tunables_are_resolved_through_paths_and_inheritance() {
+        assert_cil(
+            r#"
+            block a { tunable t = false; }
+            block b extends a { if t { allow x y : file (read); } }
+            if a.t { allow x y : file (write); }
+            "#,
+            r#"
+            (block a (tunable t false))
+            (block b (blockinherit a) (tunableif t (true (allow x y (file (read))))))
+            (tunableif a.t (true (allow x y (file (write)))))
+            "#,
+        );
+    }
+
+    #[test]
+    fn undeclared_toggle() {
+        assert_cil_error("if missing {}", "undeclared boolean or tunable `missing`");
+    }
+
+    #[test]
+    fn bool_with_invalid_initial_value() {
+        assert_cil_error("bool b = 1;", "expected `true` or `false`");
+    }
+
+    #[test]
+    fn condition_mixing_booleans_and_tunables() {
+        assert_cil_error(
+            "bool b = true; tunable t = false; if b && t {}",
+            "a condition can't mix booleans and tunables",
+        );
+    }
+
+    #[test]
+    fn macro_calls() {
+        assert_cil(
+            "m(a, .b.c, \"d\"); n();",
+            "(call m (a .b.c \"d\")) (call n)",
+        );
+    }
+
+    #[test]
+    fn macro_calls_with_default_and_named_arguments() {
+        assert_cil(
+            r#"
+            macro m(type t, bool write = false, bool exec = false) {}
+            m(a);
+            m(a, exec = true);
+            m(exec = true, t = a, write = true);
+            "#,
+            r#"
+            (macro m ((type t) (boolean write) (boolean exec)))
+            (call m (a false false))
+            (call m (a false true))
+            (call m (a true true))
+            "#,
+        );
+    }
+
+    #[test]
+    fn default_arguments_are_qualified_with_their_container() {
+        assert_cil(
+            r#"
+            block a {
+                type def_t;
+                macro m(type t = def_t, type u = .global_t) {}
+            }
+            a.m();
+            a.m(b);
+            "#,
+            r#"
+            (block a
+                (type def_t)
+                (macro m ((type t) (type u))))
+            (call a.m (a.def_t .global_t))
+            (call a.m (b .global_t))
+            "#,
+        );
+    }
+
+    #[test]
+    fn macro_call_with_missing_argument() {
+        assert_cil_error(
+            "macro m(type t) {} m();",
+            "missing argument for parameter `t` of macro `m`",
+        );
+    }
+
+    #[test]
+    fn named_arguments_to_unknown_macro() {
+        assert_cil_error(
+            "m(t = a);",
+            "unable to find macro `m` to pass arguments by name",
+        );
+    }
+
+    #[test]
+    fn conditional_with_else() {
+        assert_cil(
+            r#"
+            bool a = true;
+            bool b = false;
+            if a && !b { allow t t : file (read); } else { allow t t : file (write); }
+            "#,
+            r#"
+            (boolean a true)
+            (boolean b false)
+            (booleanif (and a (not b))
+                (true (allow t t (file (read))))
+                (false (allow t t (file (write)))))
+            "#,
+        );
+    }
+
+    #[test]
+    fn conditional_with_else_if() {
+        assert_cil(
+            r#"
+            bool a = true;
+            bool b = false;
+            if a {
+                allow t t : file (read);
+            } else if b {
+                allow t t : file (write);
+            } else {
+                allow t t : file (open);
+            }
+            "#,
+            r#"
+            (boolean a true)
+            (boolean b false)
+            (booleanif a (true (allow t t (file (read)))))
+            (booleanif (and (not a) b) (true (allow t t (file (write)))))
+            (booleanif (and (not a) (not b)) (true (allow t t (file (open)))))
+            "#,
+        );
+    }
+
+    #[test]
+    fn context_expressions() {
+        assert_cil(
+            "m(u:r:t:s0, u:r:t:s0-s1:c0);",
+            "(call m ((u r t ((s0) (s0))) (u r t ((s0) (s1 (c0))))))",
+        );
+    }
+}
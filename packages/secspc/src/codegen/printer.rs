@@ -0,0 +1,128 @@
+use symbolic_expressions::Sexp;
+
+const INDENT: &str = "    ";
+
+/// Get the number of leading elements of a CIL statement that are printed on the same line
+/// as its keyword, or `None` if the statement isn't a container and is printed inline.
+fn container_header_len(statement: &[Sexp]) -> Option<usize> {
+    let keyword = match statement.first() {
+        Some(Sexp::String(keyword)) => keyword.as_str(),
+        _ => return None,
+    };
+
+    let header_len = match keyword {
+        "block" | "optional" | "in" | "booleanif" | "tunableif" => 2,
+        "macro" => 3,
+        "true" | "false" => 1,
+        _ => return None,
+    };
+
+    Some(header_len)
+}
+
+/// Pretty-print a list of CIL statements, putting the contents of each container on separate,
+/// indented lines.
+pub fn print(statements: &[Sexp]) -> String {
+    let mut out = String::new();
+
+    for statement in statements {
+        print_statement(statement, 0, &mut out);
+    }
+
+    out
+}
+
+fn print_statement(statement: &Sexp, depth: usize, out: &mut String) {
+    for _ in 0..depth {
+        out.push_str(INDENT);
+    }
+
+    match statement {
+        Sexp::List(items) => match container_header_len(items) {
+            Some(header_len) if items.len() > header_len => {
+                let (header, body) = items.split_at(header_len);
+
+                print_items(header, out);
+                out.push('\n');
+
+                for child in body {
+                    print_statement(child, depth + 1, out);
+                }
+
+                for _ in 0..depth {
+                    out.push_str(INDENT);
+                }
+
+                out.push(')');
+            }
+            _ => print_inline(statement, out),
+        },
+        _ => print_inline(statement, out),
+    }
+
+    out.push('\n');
+}
+
+fn print_inline(expr: &Sexp, out: &mut String) {
+    match expr {
+        Sexp::String(value) => out.push_str(value),
+        Sexp::List(items) => {
+            print_items(items, out);
+            out.push(')');
+        }
+        Sexp::Empty => out.push_str("()"),
+    }
+}
+
+/// Print the opening parenthesis of a list followed by its [items], without closing it.
+fn print_items(items: &[Sexp], out: &mut String) {
+    out.push('(');
+
+    for (idx, item) in items.iter().enumerate() {
+        if idx > 0 {
+            out.push(' ');
+        }
+
+        print_inline(item, out);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn atom(value: &str) -> Sexp {
+        Sexp::String(value.to_string())
+    }
+
+    #[test]
+    fn inline_statement() {
+        let statement = Sexp::List(vec![
+            atom("allow"),
+            atom("a"),
+            atom("b"),
+            Sexp::List(vec![atom("file"), Sexp::List(vec![atom("read")])]),
+        ]);
+
+        assert_eq!("(allow a b (file (read)))\n", print(&[statement]));
+    }
+
+    #[test]
+    fn nested_containers() {
+        let statement = Sexp::List(vec![
+            atom("block"),
+            atom("a"),
+            Sexp::List(vec![atom("block"), atom("b")]),
+            Sexp::List(vec![
+                atom("block"),
+                atom("c"),
+                Sexp::List(vec![atom("type"), atom("t")]),
+            ]),
+        ]);
+
+        assert_eq!(
+            "(block a\n    (block b)\n    (block c\n        (type t)\n    )\n)\n",
+            print(&[statement])
+        );
+    }
+}
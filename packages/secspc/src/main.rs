@@ -5,14 +5,22 @@ extern crate fern;
 extern crate log;
 extern crate secsp_analysis;
 extern crate secsp_syntax;
+extern crate symbolic_expressions;
+extern crate text_unit;
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::process;
 
 use clap::App;
 use clap::Arg;
 
+use text_unit::TextRange;
+
+use secsp_analysis::macros::MacroTable;
+use secsp_analysis::toggles::ToggleTable;
 use secsp_analysis::{AnalysisDatabase, AnalysisHost};
 
+mod codegen;
 mod utils;
 
 fn setup_logger(level: log::LevelFilter) -> Result<(), fern::InitError> {
@@ -26,7 +34,7 @@ fn setup_logger(level: log::LevelFilter) -> Result<(), fern::InitError> {
             ))
         })
         .level(level)
-        .chain(std::io::stdout())
+        .chain(std::io::stderr())
         .apply()?;
     Ok(())
 }
@@ -42,6 +50,20 @@ fn main() {
                 .multiple(true)
                 .index(1),
         )
+        .arg(
+            Arg::with_name("file")
+                .short("f")
+                .long("file")
+                .help("A source file to process")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1),
+        )
+        .arg(
+            Arg::with_name("ast")
+                .long("ast")
+                .help("Print the syntax tree of each source file instead of generating CIL"),
+        )
         .arg(
             Arg::with_name("verbosity")
                 .short("v")
@@ -59,22 +81,93 @@ fn main() {
 
     setup_logger(log_level).unwrap();
 
-    let input_files = matches
-        .values_of_lossy("input")
-        .unwrap_or_else(|| vec![])
-        .iter()
+    let input_files: Vec<PathBuf> = matches
+        .values_of_lossy("file")
+        .into_iter()
+        .chain(matches.values_of_lossy("input"))
+        .flatten()
         .map(PathBuf::from)
         .collect();
 
-    let analysis_db = AnalysisDatabase::from_files(input_files)
+    let analysis_db = AnalysisDatabase::from_files(input_files.clone())
         .unwrap_or_else(|e| panic!("Unable to read input files: {}", e));
     let analysis_host = AnalysisHost::new(analysis_db);
 
     let analysis = analysis_host.analysis();
-    let source_root = analysis.source_root().expect("no source root defined");
+    let mut has_errors = false;
+
+    // Process each file after the files it imports, and only once if it's imported more than once.
+    let mut file_ids = vec![];
+
+    for path in input_files {
+        let id = analysis.file_id(path).expect("unknown input file");
+        let import_order = analysis.import_order(id).expect("couldn't resolve imports");
+
+        for id in import_order {
+            if !file_ids.contains(&id) {
+                file_ids.push(id);
+            }
+        }
+    }
+
+    // Macro calls and conditions are resolved against every file, as they are during analysis.
+    let source_root = analysis.source_root().expect("couldn't list input files");
+    let files: Vec<_> = source_root
+        .0
+        .iter()
+        .map(|&id| analysis.source_file(id).expect("couldn't parse").tree())
+        .collect();
+    let macros = MacroTable::new(files.clone());
+    let toggles = ToggleTable::new(files);
 
-    for id in source_root.0 {
+    for id in file_ids {
+        let path = analysis.file_path(id).expect("unknown input file");
+        let text = analysis.file_text(id).expect("couldn't read");
         let source = analysis.source_file(id).expect("couldn't parse");
-        println!("{}", utils::ast_to_string(&source.tree()));
+
+        if matches.is_present("ast") {
+            println!("{}", utils::ast_to_string(&source.tree()));
+            continue;
+        }
+
+        for error in source.errors() {
+            report_error(&path, &text, error.range(), &error.to_string());
+            has_errors = true;
+        }
+
+        if !source.errors().is_empty() {
+            continue;
+        }
+
+        let diagnostics = analysis.diagnostics(id).expect("couldn't analyze");
+
+        for diagnostic in &diagnostics {
+            report_error(&path, &text, diagnostic.range(), diagnostic.message());
+            has_errors = true;
+        }
+
+        if !diagnostics.is_empty() {
+            continue;
+        }
+
+        match codegen::generate_cil(&source.tree(), &macros, &toggles) {
+            Ok(statements) => print!("{}", codegen::print(&statements)),
+            Err(errors) => {
+                for error in errors {
+                    report_error(&path, &text, error.range(), error.message());
+                }
+
+                has_errors = true;
+            }
+        }
+    }
+
+    if has_errors {
+        process::exit(1);
     }
 }
+
+fn report_error(path: &Path, text: &str, range: TextRange, message: &str) {
+    let (line, col) = utils::line_col(text, range.start());
+    eprintln!("{}:{}:{}: error: {}", path.display(), line, col, message);
+}
@@ -1,3 +1,5 @@
 pub use self::ast_to_string::*;
+pub use self::line_col::*;
 
 mod ast_to_string;
+mod line_col;
@@ -0,0 +1,11 @@
+use text_unit::TextUnit;
+
+/// Convert a byte [offset] into [text] to a 1-based line and column pair.
+pub fn line_col(text: &str, offset: TextUnit) -> (usize, usize) {
+    let offset = (offset.to_usize()).min(text.len());
+    let prefix = &text[..offset];
+    let line = prefix.matches('\n').count() + 1;
+    let line_start = prefix.rfind('\n').map(|idx| idx + 1).unwrap_or(0);
+
+    (line, prefix[line_start..].chars().count() + 1)
+}
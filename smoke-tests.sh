@@ -5,7 +5,7 @@ set -e
 SCRIPTPATH=`pwd`
 
 if [ -z "$CSPC" ]; then 
-    CSPC="$SCRIPTPATH/target/debug/cspc"
+    CSPC="$SCRIPTPATH/target/debug/secspc"
 fi
 
 if [ -z "$SEXP_CMP" ]; then